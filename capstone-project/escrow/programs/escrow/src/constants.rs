@@ -1,22 +1,13 @@
-// ASSIGNMENT: Define constants for the escrow program
+// CONCEPT: Constants Module
+// Reference: Rust modules - https://doc.rust-lang.org/book/ch07-02-defining-modules-to-control-scope-and-privacy.html
+// Separating constants makes the code more maintainable and easier to modify
 
-// TODO 1: Define a seed prefix for the escrow PDA
-// This is used to derive the escrow account address
-// Example: pub const ESCROW_SEED: &[u8] = b"escrow";
-//
-// HINT: Use a descriptive name like "escrow" or "escrow_state"
-
-// TODO 2: (OPTIONAL) Define a minimum escrow amount
-// This prevents spam or dust escrows
-// Example: pub const MIN_ESCROW_AMOUNT: u64 = 1_000_000; // 0.001 SOL
-//
-// HINT: 1 SOL = 1,000,000,000 lamports
+// PDA SEEDS
 
-// TODO 3: (OPTIONAL) Define a maximum escrow duration
-// After this time, escrows automatically expire
-// Example: pub const MAX_ESCROW_DURATION: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
-//
-// HINT: Calculate seconds: days * hours * minutes * seconds
+// Seed prefix for the escrow PDA
+// Full seeds: [ESCROW_SEED, maker pubkey, seed.to_le_bytes()]
+// The trailing u64 seed lets one maker have many open escrows at once
+pub const ESCROW_SEED: &[u8] = b"escrow";
 
 // QUESTION FOR STUDENTS:
 // Why use constants instead of hardcoding values in the program?
@@ -28,6 +19,6 @@
 // 4. Makes testing easier (can override in tests)
 
 // BONUS: Think about other constants you might need
+// - Minimum escrow amount (to prevent dust escrows)?
+// - Maximum escrow duration (for expiring offers)?
 // - Fee percentages?
-// - Maximum number of concurrent escrows per user?
-// - Minimum time before cancellation?
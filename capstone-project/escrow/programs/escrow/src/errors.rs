@@ -1,36 +1,22 @@
 use anchor_lang::prelude::*;
 
-// ASSIGNMENT: Define custom errors for the escrow program
-//
-// REFERENCE: https://www.anchor-lang.com/docs/errors
+// CONCEPT: Custom Error Module
+// Reference: https://www.anchor-lang.com/docs/errors
+// Each error gets a unique error code (6000, 6001, 6002, etc.)
 
 #[error_code]
 pub enum EscrowError {
-    // TODO 1: Add an error for when amounts are invalid (e.g., 0 or too large)
-    // Example: #[msg("Amount must be greater than 0")]
-    // InvalidAmount,
-
-    // TODO 2: Add an error for when the wrong taker tries to accept
-    // (if escrow specifies a specific taker)
-    // UnauthorizedTaker,
-
-    // TODO 3: Add an error for when someone tries to cancel an escrow
-    // that has already been accepted
-    // EscrowAlreadyCompleted,
-
-    // TODO 4: Add an error for when the escrow has expired
-    // EscrowExpired,
-
-    // TODO 5: Add an error for when someone other than the maker
-    // tries to cancel the escrow
-    // NotMaker,
-
-    // BONUS TODO: Add more specific error messages for different scenarios
-    // Think about what could go wrong in an escrow:
-    // - Insufficient funds
-    // - Arithmetic overflow
-    // - Invalid state transitions
-    // - Etc.
+    #[msg("Amount must be greater than 0")]
+    InvalidAmount,
+
+    #[msg("Only the designated taker can accept this escrow")]
+    UnauthorizedTaker,
+
+    #[msg("Only the maker can cancel this escrow")]
+    NotMaker,
+
+    #[msg("Maker cannot accept their own escrow")]
+    MakerCannotAccept,
 }
 
 // ERROR MESSAGE BEST PRACTICES:
@@ -54,3 +40,7 @@ pub enum EscrowError {
 // 2. Clear error messages for users
 // 3. Proper error handling in tests
 // 4. Professional code quality
+
+// NOTE: There is no "EscrowAlreadyCompleted" error.
+// Accepting or cancelling closes the escrow account, so a second attempt
+// fails during account validation (the account no longer exists).
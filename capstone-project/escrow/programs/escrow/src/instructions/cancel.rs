@@ -1,80 +1,45 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer};
 use crate::state::Escrow;
-// use crate::errors::EscrowError; // Uncomment when you define errors
+use crate::errors::EscrowError;
+use crate::constants::*;
 
-// ASSIGNMENT: Complete the cancel_escrow instruction
-//
-// This instruction allows the maker to cancel the escrow and get their funds back
-// This should only be allowed BEFORE someone accepts the escrow
+// INSTRUCTION: Cancel Escrow
+// Lets the maker cancel an open escrow and get their funds back
+// Only possible BEFORE a taker accepts (accepting closes the escrow)
 //
 // REFERENCE: https://www.anchor-lang.com/docs/pdas
 
 pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
     let escrow = &ctx.accounts.escrow;
 
-    // TODO 1: Add any additional validation
-    // For example, check if escrow has expired (if you implemented expiration)
-    // Or check the escrow status if you added that field
-
-    // TODO 2: Transfer the escrowed funds back to the maker
-    // The escrow PDA needs to sign this transaction
-    //
-    // HINT: Similar to accept_escrow, use CpiContext::new_with_signer
-    //
-    // let seeds = &[
-    //     b"escrow",
-    //     escrow.maker.as_ref(),
-    //     // ... any other seeds ...
-    //     &[escrow.bump],
-    // ];
-    // let signer_seeds = &[&seeds[..]];
-    //
-    // let transfer_accounts = Transfer {
-    //     from: ???,
-    //     to: ???,
-    // };
-    // let cpi_ctx = CpiContext::new_with_signer(???, transfer_accounts, signer_seeds);
-    // transfer(cpi_ctx, escrow.maker_amount)?;
-
-    // TODO 3: Log cancellation message
-    // msg!("Escrow cancelled by maker. Refunded {} lamports", escrow.maker_amount);
-
-    // NOTE: The escrow account will be closed and rent returned to maker
-    // because of the close constraint in CancelEscrow struct
+    // CONCEPT: Refund via Account Closure
+    // The escrow PDA holds exactly maker_amount + rent. The `close = maker`
+    // constraint moves ALL of its lamports to the maker after this handler
+    // returns, so the refund and the rent come back in one step.
+    msg!(
+        "Escrow cancelled by maker. Refunded {} lamports",
+        escrow.maker_amount
+    );
 
     Ok(())
 }
 
-// ASSIGNMENT: Complete the CancelEscrow accounts struct
-
 #[derive(Accounts)]
 pub struct CancelEscrow<'info> {
-    // TODO 1: Define the escrow account
-    // This account needs to:
-    // - Be mutable (we're transferring from it)
-    // - Be a PDA with matching seeds
-    // - Be closed after execution (rent to maker)
-    // - Verify the maker owns it (has_one constraint)
-    //
-    // #[account(
-    //     mut,
-    //     seeds = [???],
-    //     bump = escrow.bump,
-    //     close = ???,
-    //     has_one = maker
-    // )]
-    // pub escrow: Account<'info, Escrow>,
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, escrow.maker.as_ref(), &escrow.seed.to_le_bytes()],
+        bump = escrow.bump,
+        close = maker,
+        has_one = maker @ EscrowError::NotMaker
+    )]
+    pub escrow: Account<'info, Escrow>,
 
-    // TODO 2: Define the maker account
-    // Must be a signer (only maker can cancel)
-    // Must be mutable (receives the refund and rent)
-    //
-    // #[account(mut)]
-    // pub maker: Signer<'info>,
+    // Must sign (only the maker can cancel) and be mutable (receives the refund)
+    #[account(mut)]
+    pub maker: Signer<'info>,
 
-    // TODO 3: Define the system program
-    // pub system_program: Program<'info, System>,
+    pub system_program: Program<'info, System>,
 }
 
 // QUESTIONS FOR STUDENTS:
@@ -92,6 +57,7 @@ pub struct CancelEscrow<'info> {
 //    - Always allow: Maker can always cancel (more control for maker)
 //    - Never allow: Once created, it must be accepted or expire (more trustworthy)
 //    - Conditional: Allow if not expired, or if taker hasn't shown interest
+//    This program always allows it.
 //
 // 4. What happens to the rent when we close the account?
 //    Answer: It's returned to the address specified in the close constraint.
@@ -104,19 +70,6 @@ pub struct CancelEscrow<'info> {
 // 3. DOUBLE-SPEND: Can't cancel twice (account is closed after first cancel)
 // 4. GRIEFING: Maker could cancel right before taker accepts (consider time locks)
 
-// BONUS CHALLENGES:
-//
-// 1. Add a time lock: Maker can only cancel after X time has passed
-//    (prevents immediate cancellation)
-//
-// 2. Add a penalty: If maker cancels, some funds go to a protocol fee
-//    (discourages spam escrows)
-//
-// 3. Add expiration: Auto-cancel if escrow is past expiration date
-//    (implement as a separate instruction or in cancel logic)
-//
-// 4. Emit a cancellation event for indexing
-
 // DESIGN PATTERN: Why separate cancel from accept?
 //
 // We could have one "close_escrow" instruction that handles both accepting
@@ -1,19 +1,12 @@
-// ASSIGNMENT: Module Organization
-//
-// This file exports all instruction modules so they can be used in lib.rs
-//
-// REFERENCE: https://doc.rust-lang.org/book/ch07-02-defining-modules-to-control-scope-and-privacy.html
+// CONCEPT: Module Organization
+// Reference: https://doc.rust-lang.org/book/ch07-02-defining-modules-to-control-scope-and-privacy.html
+// This file declares and re-exports all instruction modules
 
-// TODO: Uncomment these as you implement each instruction
-// pub mod initialize;
-// pub mod accept;
-// pub mod cancel;
+pub mod initialize;
+pub mod accept;
+pub mod cancel;
 
-// TODO: Re-export everything from the modules
-// pub use initialize::*;
-// pub use accept::*;
-// pub use cancel::*;
-
-// BONUS: If you add more instructions, add them here:
-// pub mod update; // For updating escrow terms before acceptance
-// pub use update::*;
+// Re-export everything so lib.rs can just `use instructions::*;`
+pub use initialize::*;
+pub use accept::*;
+pub use cancel::*;
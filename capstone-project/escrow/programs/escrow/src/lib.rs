@@ -1,8 +1,5 @@
 // CAPSTONE PROJECT: Simple Escrow Program
 //
-// This is an assignment template. You need to complete the TODOs throughout
-// the codebase to create a working escrow program.
-//
 // WHAT IS AN ESCROW?
 // An escrow is a smart contract that holds funds from two parties and
 // atomically swaps them. Both parties get what they want, or nobody does.
@@ -24,14 +21,12 @@
 
 use anchor_lang::prelude::*;
 
-// TODO: Uncomment these when you create the files
-// mod state;
-// mod errors;
-// mod constants;
-// mod instructions;
+mod state;
+mod errors;
+mod constants;
+mod instructions;
 
-// TODO: Uncomment this when you implement instructions
-// use instructions::*;
+use instructions::*;
 
 // Program ID - this will be different when you build
 declare_id!("DpyyAnbMVMR5rwBuRWntUGq9RMM9gmyEKDjrUtc6pTvw");
@@ -40,34 +35,27 @@ declare_id!("DpyyAnbMVMR5rwBuRWntUGq9RMM9gmyEKDjrUtc6pTvw");
 pub mod escrow {
     use super::*;
 
-    // INSTRUCTION 1: Initialize Escrow
-    // Creates a new escrow with maker's offer
-    //
-    // TODO: Implement this instruction
-    // pub fn initialize_escrow(
-    //     ctx: Context<InitializeEscrow>,
-    //     maker_amount: u64,
-    //     taker_amount: u64,
-    //     taker: Option<Pubkey>,
-    // ) -> Result<()> {
-    //     instructions::initialize::initialize_escrow(ctx, maker_amount, taker_amount, taker)
-    // }
+    /// Creates a new escrow and locks the maker's offered lamports in it
+    /// `seed` makes the escrow PDA unique so a maker can have several open offers
+    pub fn initialize_escrow(
+        ctx: Context<InitializeEscrow>,
+        seed: u64,
+        maker_amount: u64,
+        taker_amount: u64,
+        taker: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::initialize::initialize_escrow(ctx, seed, maker_amount, taker_amount, taker)
+    }
 
-    // INSTRUCTION 2: Accept Escrow
-    // Allows a taker to accept the escrow offer
-    //
-    // TODO: Implement this instruction
-    // pub fn accept_escrow(ctx: Context<AcceptEscrow>) -> Result<()> {
-    //     instructions::accept::accept_escrow(ctx)
-    // }
+    /// Executes the atomic swap: taker pays the maker, escrow pays the taker
+    pub fn accept_escrow(ctx: Context<AcceptEscrow>) -> Result<()> {
+        instructions::accept::accept_escrow(ctx)
+    }
 
-    // INSTRUCTION 3: Cancel Escrow
-    // Allows maker to cancel and get funds back
-    //
-    // TODO: Implement this instruction
-    // pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
-    //     instructions::cancel::cancel_escrow(ctx)
-    // }
+    /// Cancels the escrow and refunds the maker (deposit + rent)
+    pub fn cancel_escrow(ctx: Context<CancelEscrow>) -> Result<()> {
+        instructions::cancel::cancel_escrow(ctx)
+    }
 
     // BONUS INSTRUCTION IDEAS:
     //
@@ -79,7 +67,7 @@ pub mod escrow {
 // PROGRAM FLOW:
 //
 // Happy Path:
-// 1. Maker calls initialize_escrow(10 SOL, 5 SOL)
+// 1. Maker calls initialize_escrow(seed, 10 SOL, 5 SOL, None)
 //    → Escrow PDA created, 10 SOL deposited
 // 2. Taker calls accept_escrow()
 //    → Taker sends 5 SOL to maker
@@ -87,7 +75,7 @@ pub mod escrow {
 //    → Escrow account closed
 //
 // Cancellation Path:
-// 1. Maker calls initialize_escrow(10 SOL, 5 SOL)
+// 1. Maker calls initialize_escrow(seed, 10 SOL, 5 SOL, None)
 //    → Escrow PDA created, 10 SOL deposited
 // 2. Maker calls cancel_escrow()
 //    → 10 SOL returned to maker
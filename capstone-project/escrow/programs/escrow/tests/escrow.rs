@@ -0,0 +1,237 @@
+// CONCEPT: Rust Integration Tests with solana-program-test
+// Reference: https://docs.rs/solana-program-test
+// ProgramTest spins up a local bank (no validator) and loads the compiled
+// program from target/deploy/escrow.so. Anchor's CPI helpers only work on
+// the SBF target, so the suite runs against the real build:
+//
+//   cargo test-sbf     # builds escrow.so, then runs these tests
+//
+// The `test-sbf` feature (enabled by cargo test-sbf) keeps a plain
+// `cargo test` from trying to load a program that hasn't been built.
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey, system_program};
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+// EscrowError codes (Anchor numbers custom errors from 6000, in enum order)
+const UNAUTHORIZED_TAKER: u32 = 6001;
+const NOT_MAKER: u32 = 6002;
+const MAKER_CANNOT_ACCEPT: u32 = 6003;
+// Anchor's AccountNotInitialized: the escrow was closed by the first accept
+const ACCOUNT_NOT_INITIALIZED: u32 = 3012;
+
+const MAKER_AMOUNT: u64 = 2 * LAMPORTS_PER_SOL;
+const TAKER_AMOUNT: u64 = LAMPORTS_PER_SOL;
+
+struct Setup {
+    context: ProgramTestContext,
+    maker: Keypair,
+    taker: Keypair,
+}
+
+async fn setup() -> Setup {
+    let mut program_test = ProgramTest::new("escrow", escrow::ID, None);
+    program_test.prefer_bpf(true);
+    let maker = Keypair::new();
+    let taker = Keypair::new();
+    for wallet in [&maker, &taker] {
+        program_test.add_account(
+            wallet.pubkey(),
+            Account {
+                lamports: 10 * LAMPORTS_PER_SOL,
+                owner: system_program::ID,
+                ..Account::default()
+            },
+        );
+    }
+    let context = program_test.start_with_context().await;
+    Setup { context, maker, taker }
+}
+
+fn escrow_pda(maker: &Pubkey, seed: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"escrow", maker.as_ref(), &seed.to_le_bytes()], &escrow::ID).0
+}
+
+fn initialize_ix(maker: &Pubkey, seed: u64, taker: Option<Pubkey>) -> Instruction {
+    Instruction {
+        program_id: escrow::ID,
+        accounts: escrow::accounts::InitializeEscrow {
+            escrow: escrow_pda(maker, seed),
+            maker: *maker,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: escrow::instruction::InitializeEscrow {
+            seed,
+            maker_amount: MAKER_AMOUNT,
+            taker_amount: TAKER_AMOUNT,
+            taker,
+        }
+        .data(),
+    }
+}
+
+fn accept_ix(escrow: Pubkey, maker: &Pubkey, taker: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: escrow::ID,
+        accounts: escrow::accounts::AcceptEscrow {
+            escrow,
+            maker: *maker,
+            taker: *taker,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: escrow::instruction::AcceptEscrow {}.data(),
+    }
+}
+
+fn cancel_ix(escrow: Pubkey, maker: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: escrow::ID,
+        accounts: escrow::accounts::CancelEscrow {
+            escrow,
+            maker: *maker,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: escrow::instruction::CancelEscrow {}.data(),
+    }
+}
+
+// The context payer covers fees, so wallet balances only move by escrow amounts
+async fn send(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn balance(context: &mut ProgramTestContext, key: Pubkey) -> u64 {
+    context.banks_client.get_balance(key).await.unwrap()
+}
+
+fn assert_custom_error(result: Result<(), BanksClientError>, code: u32) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(code))
+    );
+}
+
+#[tokio::test]
+async fn initialize_locks_maker_funds() {
+    let Setup { mut context, maker, .. } = setup().await;
+    let escrow = escrow_pda(&maker.pubkey(), 1);
+    let maker_before = balance(&mut context, maker.pubkey()).await;
+
+    send(&mut context, initialize_ix(&maker.pubkey(), 1, None), &maker).await.unwrap();
+
+    let account = context.banks_client.get_account(escrow).await.unwrap().unwrap();
+    let rent = maker_before - MAKER_AMOUNT - balance(&mut context, maker.pubkey()).await;
+    assert_eq!(account.owner, escrow::ID);
+    assert_eq!(account.lamports, MAKER_AMOUNT + rent);
+}
+
+#[tokio::test]
+async fn accept_swaps_atomically_and_closes_escrow() {
+    let Setup { mut context, maker, taker } = setup().await;
+    let escrow = escrow_pda(&maker.pubkey(), 1);
+    let maker_before = balance(&mut context, maker.pubkey()).await;
+    let taker_before = balance(&mut context, taker.pubkey()).await;
+
+    send(&mut context, initialize_ix(&maker.pubkey(), 1, None), &maker).await.unwrap();
+    send(&mut context, accept_ix(escrow, &maker.pubkey(), &taker.pubkey()), &taker)
+        .await
+        .unwrap();
+
+    // Maker swapped MAKER_AMOUNT for TAKER_AMOUNT and got the rent back
+    assert_eq!(
+        balance(&mut context, maker.pubkey()).await,
+        maker_before - MAKER_AMOUNT + TAKER_AMOUNT
+    );
+    assert_eq!(
+        balance(&mut context, taker.pubkey()).await,
+        taker_before - TAKER_AMOUNT + MAKER_AMOUNT
+    );
+    assert!(context.banks_client.get_account(escrow).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn cancel_refunds_maker_and_closes_escrow() {
+    let Setup { mut context, maker, .. } = setup().await;
+    let escrow = escrow_pda(&maker.pubkey(), 1);
+    let maker_before = balance(&mut context, maker.pubkey()).await;
+
+    send(&mut context, initialize_ix(&maker.pubkey(), 1, None), &maker).await.unwrap();
+    send(&mut context, cancel_ix(escrow, &maker.pubkey()), &maker).await.unwrap();
+
+    // Deposit and rent both come back
+    assert_eq!(balance(&mut context, maker.pubkey()).await, maker_before);
+    assert!(context.banks_client.get_account(escrow).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn maker_cannot_accept_own_escrow() {
+    let Setup { mut context, maker, .. } = setup().await;
+    let escrow = escrow_pda(&maker.pubkey(), 1);
+
+    send(&mut context, initialize_ix(&maker.pubkey(), 1, None), &maker).await.unwrap();
+    let result = send(&mut context, accept_ix(escrow, &maker.pubkey(), &maker.pubkey()), &maker).await;
+
+    assert_custom_error(result, MAKER_CANNOT_ACCEPT);
+}
+
+#[tokio::test]
+async fn only_designated_taker_can_accept() {
+    let Setup { mut context, maker, taker } = setup().await;
+    let escrow = escrow_pda(&maker.pubkey(), 1);
+    let stranger = Keypair::new();
+
+    send(&mut context, initialize_ix(&maker.pubkey(), 1, Some(stranger.pubkey())), &maker)
+        .await
+        .unwrap();
+    let result = send(&mut context, accept_ix(escrow, &maker.pubkey(), &taker.pubkey()), &taker).await;
+
+    assert_custom_error(result, UNAUTHORIZED_TAKER);
+}
+
+#[tokio::test]
+async fn non_maker_cannot_cancel() {
+    let Setup { mut context, maker, taker } = setup().await;
+    let escrow = escrow_pda(&maker.pubkey(), 1);
+
+    send(&mut context, initialize_ix(&maker.pubkey(), 1, None), &maker).await.unwrap();
+    let result = send(&mut context, cancel_ix(escrow, &taker.pubkey()), &taker).await;
+
+    assert_custom_error(result, NOT_MAKER);
+    assert!(context.banks_client.get_account(escrow).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn escrow_cannot_be_accepted_twice() {
+    let Setup { mut context, maker, taker } = setup().await;
+    let escrow = escrow_pda(&maker.pubkey(), 1);
+
+    send(&mut context, initialize_ix(&maker.pubkey(), 1, None), &maker).await.unwrap();
+    send(&mut context, accept_ix(escrow, &maker.pubkey(), &taker.pubkey()), &taker)
+        .await
+        .unwrap();
+    let result = send(&mut context, accept_ix(escrow, &maker.pubkey(), &taker.pubkey()), &taker).await;
+
+    assert_custom_error(result, ACCOUNT_NOT_INITIALIZED);
+}
@@ -1,16 +1,322 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
 import { Escrow } from "../target/types/escrow";
+import { expect } from "chai";
+
+// CONCEPT: Anchor Testing Framework
+// Reference: https://www.anchor-lang.com/docs/testing
+// Each test uses fresh keypairs so escrows never collide between runs
 
 describe("escrow", () => {
-  // Configure the client to use the local cluster.
+  // Configure the client to use the local cluster
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.escrow as Program<Escrow>;
+  const provider = anchor.getProvider();
+
+  const maker = anchor.web3.Keypair.generate();
+  const taker = anchor.web3.Keypair.generate();
+  const stranger = anchor.web3.Keypair.generate();
+
+  const MAKER_AMOUNT = new BN(1_000_000_000); // 1 SOL offered
+  const TAKER_AMOUNT = new BN(500_000_000); // 0.5 SOL asked
+
+  // Each escrow gets its own seed so tests stay independent
+  let nextSeed = 0;
+
+  // Helper: derive the escrow PDA for a maker + seed
+  function findEscrowPda(makerKey: anchor.web3.PublicKey, seed: BN): anchor.web3.PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), makerKey.toBuffer(), seed.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    return pda;
+  }
+
+  // Helper: fund a keypair from the local validator faucet
+  async function airdrop(to: anchor.web3.PublicKey, lamports: number) {
+    const signature = await provider.connection.requestAirdrop(to, lamports);
+    const latestBlockHash = await provider.connection.getLatestBlockhash();
+    await provider.connection.confirmTransaction({
+      blockhash: latestBlockHash.blockhash,
+      lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+      signature,
+    });
+  }
+
+  // Helper: create a funded escrow and return its seed + PDA
+  async function createEscrow(taker: anchor.web3.PublicKey | null) {
+    const seed = new BN(nextSeed++);
+    const escrowPda = findEscrowPda(maker.publicKey, seed);
+
+    await program.methods
+      .initializeEscrow(seed, MAKER_AMOUNT, TAKER_AMOUNT, taker)
+      .accounts({
+        escrow: escrowPda,
+        maker: maker.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([maker])
+      .rpc();
+
+    return { seed, escrowPda };
+  }
+
+  before(async () => {
+    await airdrop(maker.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
+    await airdrop(taker.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
+    await airdrop(stranger.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
+
+    console.log("\n📋 Test Setup:");
+    console.log("   Program ID:", program.programId.toString());
+    console.log("   Maker:", maker.publicKey.toString());
+    console.log("   Taker:", taker.publicKey.toString());
+  });
+
+  // ==============================================
+  // TEST 1: Initialize Escrow
+  // ==============================================
+  it("Initializes an escrow and locks the maker's funds", async () => {
+    console.log("\n🧪 TEST 1: Initialize Escrow");
+
+    const seed = new BN(nextSeed++);
+    const escrowPda = findEscrowPda(maker.publicKey, seed);
+    const makerBalanceBefore = await provider.connection.getBalance(maker.publicKey);
+
+    await program.methods
+      .initializeEscrow(seed, MAKER_AMOUNT, TAKER_AMOUNT, taker.publicKey)
+      .accounts({
+        escrow: escrowPda,
+        maker: maker.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([maker])
+      .rpc();
+
+    const escrowAccount = await program.account.escrow.fetch(escrowPda);
+    expect(escrowAccount.maker.toString()).to.equal(maker.publicKey.toString());
+    expect(escrowAccount.taker.toString()).to.equal(taker.publicKey.toString());
+    expect(escrowAccount.makerAmount.toNumber()).to.equal(MAKER_AMOUNT.toNumber());
+    expect(escrowAccount.takerAmount.toNumber()).to.equal(TAKER_AMOUNT.toNumber());
+    expect(escrowAccount.seed.toNumber()).to.equal(seed.toNumber());
+    expect(escrowAccount.createdAt.toNumber()).to.be.greaterThan(0);
+
+    // Escrow holds the offered amount on top of its rent
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(
+      program.account.escrow.size
+    );
+    const escrowBalance = await provider.connection.getBalance(escrowPda);
+    expect(escrowBalance).to.equal(rent + MAKER_AMOUNT.toNumber());
+
+    const makerBalanceAfter = await provider.connection.getBalance(maker.publicKey);
+    expect(makerBalanceBefore - makerBalanceAfter).to.be.at.least(MAKER_AMOUNT.toNumber());
+
+    console.log("   ✅ Escrow created at:", escrowPda.toString());
+  });
+
+  // ==============================================
+  // TEST 2: Accept Escrow (Atomic Swap)
+  // ==============================================
+  it("Accepts an escrow and swaps funds atomically", async () => {
+    console.log("\n🧪 TEST 2: Accept Escrow");
+
+    const { escrowPda } = await createEscrow(taker.publicKey);
+    const escrowBalance = await provider.connection.getBalance(escrowPda);
+    const makerBalanceBefore = await provider.connection.getBalance(maker.publicKey);
+    const takerBalanceBefore = await provider.connection.getBalance(taker.publicKey);
+
+    await program.methods
+      .acceptEscrow()
+      .accounts({
+        escrow: escrowPda,
+        maker: maker.publicKey,
+        taker: taker.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([taker])
+      .rpc();
+
+    const makerBalanceAfter = await provider.connection.getBalance(maker.publicKey);
+    const takerBalanceAfter = await provider.connection.getBalance(taker.publicKey);
+
+    // Maker receives the taker's payment plus the escrow rent back
+    const rent = escrowBalance - MAKER_AMOUNT.toNumber();
+    expect(makerBalanceAfter - makerBalanceBefore).to.equal(TAKER_AMOUNT.toNumber() + rent);
+
+    // Taker pays TAKER_AMOUNT + fee and receives MAKER_AMOUNT
+    const takerGain = takerBalanceAfter - takerBalanceBefore;
+    const expectedGain = MAKER_AMOUNT.toNumber() - TAKER_AMOUNT.toNumber();
+    expect(takerGain).to.be.at.most(expectedGain);
+    expect(takerGain).to.be.greaterThan(expectedGain - 10_000);
+
+    // Escrow account is closed
+    const escrowInfo = await provider.connection.getAccountInfo(escrowPda);
+    expect(escrowInfo).to.equal(null);
+
+    console.log("   ✅ Swap complete, escrow closed");
+  });
+
+  // ==============================================
+  // TEST 3: Accept Open Escrow
+  // ==============================================
+  it("Lets anyone accept an open escrow (taker = None)", async () => {
+    console.log("\n🧪 TEST 3: Accept Open Escrow");
+
+    const { escrowPda } = await createEscrow(null);
+
+    await program.methods
+      .acceptEscrow()
+      .accounts({
+        escrow: escrowPda,
+        maker: maker.publicKey,
+        taker: stranger.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([stranger])
+      .rpc();
+
+    const escrowInfo = await provider.connection.getAccountInfo(escrowPda);
+    expect(escrowInfo).to.equal(null);
+
+    console.log("   ✅ Open escrow accepted by a stranger");
+  });
+
+  // ==============================================
+  // TEST 4: Cancel Escrow
+  // ==============================================
+  it("Cancels an escrow and refunds the maker", async () => {
+    console.log("\n🧪 TEST 4: Cancel Escrow");
+
+    const { escrowPda } = await createEscrow(taker.publicKey);
+    const escrowBalance = await provider.connection.getBalance(escrowPda);
+    const makerBalanceBefore = await provider.connection.getBalance(maker.publicKey);
+
+    await program.methods
+      .cancelEscrow()
+      .accounts({
+        escrow: escrowPda,
+        maker: maker.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([maker])
+      .rpc();
+
+    // Maker gets the deposit and the rent back (minus the tx fee)
+    const makerBalanceAfter = await provider.connection.getBalance(maker.publicKey);
+    expect(makerBalanceAfter - makerBalanceBefore).to.be.at.most(escrowBalance);
+    expect(makerBalanceAfter - makerBalanceBefore).to.be.greaterThan(escrowBalance - 10_000);
+
+    const escrowInfo = await provider.connection.getAccountInfo(escrowPda);
+    expect(escrowInfo).to.equal(null);
+
+    console.log("   ✅ Maker refunded, escrow closed");
+  });
+
+  // ==============================================
+  // ERROR TESTS
+  // ==============================================
+
+  it("Fails to initialize with 0 lamports", async () => {
+    console.log("\n🧪 TEST: Error - Zero amount");
+
+    const seed = new BN(nextSeed++);
+    const escrowPda = findEscrowPda(maker.publicKey, seed);
+
+    try {
+      await program.methods
+        .initializeEscrow(seed, new BN(0), TAKER_AMOUNT, null)
+        .accounts({
+          escrow: escrowPda,
+          maker: maker.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+
+      expect.fail("Should have thrown InvalidAmount error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("InvalidAmount");
+      console.log("   ✅ Correctly rejected: InvalidAmount");
+    }
+  });
+
+  it("Fails when the wrong taker accepts", async () => {
+    console.log("\n🧪 TEST: Error - Wrong taker");
+
+    const { escrowPda } = await createEscrow(taker.publicKey);
+
+    try {
+      await program.methods
+        .acceptEscrow()
+        .accounts({
+          escrow: escrowPda,
+          maker: maker.publicKey,
+          taker: stranger.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([stranger])
+        .rpc();
+
+      expect.fail("Should have thrown UnauthorizedTaker error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("UnauthorizedTaker");
+      console.log("   ✅ Correctly rejected: UnauthorizedTaker");
+    }
+  });
+
+  it("Fails when a non-maker cancels", async () => {
+    console.log("\n🧪 TEST: Error - Non-maker cancel");
+
+    const { escrowPda } = await createEscrow(null);
+
+    try {
+      await program.methods
+        .cancelEscrow()
+        .accounts({
+          escrow: escrowPda,
+          maker: stranger.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([stranger])
+        .rpc();
+
+      expect.fail("Should have rejected the non-maker");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("NotMaker");
+      console.log("   ✅ Correctly rejected: NotMaker");
+    }
+  });
+
+  it("Fails to cancel an escrow twice", async () => {
+    console.log("\n🧪 TEST: Error - Double cancel");
+
+    const { escrowPda } = await createEscrow(null);
+
+    await program.methods
+      .cancelEscrow()
+      .accounts({
+        escrow: escrowPda,
+        maker: maker.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([maker])
+      .rpc();
+
+    try {
+      await program.methods
+        .cancelEscrow()
+        .accounts({
+          escrow: escrowPda,
+          maker: maker.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+      expect.fail("Should have failed on a closed escrow");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("AccountNotInitialized");
+      console.log("   ✅ Correctly rejected: escrow already closed");
+    }
   });
 });
@@ -15,22 +15,59 @@ declare_id!("4jzb27LPnWxniBfZ78suu7dvfKmmuX5pRHqKcje6NKyY");
 pub mod vault {
     use super::*;
 
+    // INSTRUCTION 0: Initialize Counter
+    // Creates the per-owner counter that hands out vault ids (called once per owner)
+    pub fn initialize_counter(ctx: Context<InitializeCounter>) -> Result<()> {
+        let counter = &mut ctx.accounts.counter;
+
+        counter.owner = ctx.accounts.user.key();
+        counter.bump = ctx.bumps.counter;
+        counter.next_vault_id = 0;
+
+        msg!("Vault counter initialized for owner: {:?}", counter.owner);
+        Ok(())
+    }
+
     // INSTRUCTION 1: Initialize
     // This creates a new vault account for a user
     // CONCEPT: Context<T> - https://www.anchor-lang.com/docs/the-accounts-struct
     // Context provides access to accounts and program metadata
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    pub fn initialize(ctx: Context<Initialize>, label: Option<String>) -> Result<()> {
+        // Labels are stored on-chain, so their size must be bounded
+        if let Some(label) = &label {
+            require!(label.len() <= MAX_LABEL_LEN, VaultError::LabelTooLong);
+        }
+
         let vault = &mut ctx.accounts.vault;
+        let counter = &mut ctx.accounts.counter;
 
         // Store the owner's public key
+        // The creator is kept separately: it is part of the PDA seeds and never
+        // changes, while `owner` can be handed over with propose/accept_owner
         vault.owner = ctx.accounts.user.key();
+        vault.creator = ctx.accounts.user.key();
+        vault.pending_owner = None;
+
+        // CONCEPT: Counter-based PDA
+        // The id comes from the owner's counter, which only ever goes up,
+        // so every vault gets fresh seeds - even after older ones are closed
+        vault.id = counter.next_vault_id;
+        vault.label = label;
+        counter.next_vault_id = counter.next_vault_id
+            .checked_add(1)
+            .ok_or(VaultError::ArithmeticOverflow)?;
 
         // CONCEPT: Bumps - https://www.anchor-lang.com/docs/pdas
         // ctx.bumps provides the bump seed used to derive the PDA
         // We store it so we can use it later for signing
         vault.bump = ctx.bumps.vault;
 
-        msg!("Vault initialized for owner: {:?}", vault.owner);
+        // Start the bookkeeping from zero; rent is not counted as a deposit
+        vault.total_deposited = 0;
+        vault.total_withdrawn = 0;
+        vault.last_activity = Clock::get()?.unix_timestamp;
+
+        msg!("Vault {} initialized for owner: {:?}", vault.id, vault.owner);
         Ok(())
     }
 
@@ -57,7 +94,26 @@ pub mod vault {
         // Execute the transfer
         transfer(cpi_context, amount)?;
 
-        msg!("Deposited {} lamports to vault", amount);
+        // CONCEPT: Tracking Balances in Account Data
+        // Raw lamports include the rent deposit, so we record user funds separately
+        let vault = &mut ctx.accounts.vault;
+        vault.total_deposited = vault.total_deposited
+            .checked_add(amount)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        vault.last_activity = Clock::get()?.unix_timestamp;
+
+        msg!("Deposited {} lamports to vault. Net deposits: {}", amount, vault.net_deposits());
+
+        // CONCEPT: Events - https://www.anchor-lang.com/docs/features/events
+        // emit! logs a typed struct that clients and indexers can decode
+        emit!(Deposited {
+            owner: vault.owner,
+            vault: vault.key(),
+            funder: ctx.accounts.user.key(),
+            amount,
+            vault_balance: vault.to_account_info().lamports(),
+            timestamp: vault.last_activity,
+        });
         Ok(())
     }
 
@@ -82,6 +138,17 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         VaultError::InsufficientFunds
     );
 
+    // CONCEPT: Rent Exemption - https://docs.solana.com/developing/programming-model/accounts#rent-exemption
+    // Part of the vault's balance is the rent deposit that keeps the account alive.
+    // Only lamports ABOVE the rent-exempt minimum for its data size can be withdrawn;
+    // emptying the vault completely goes through close_vault instead.
+    let rent_minimum = Rent::get()?.minimum_balance(vault.to_account_info().data_len());
+    let withdrawable = vault_lamports.saturating_sub(rent_minimum);
+    require!(
+        amount <= withdrawable,
+        VaultError::BelowRentExemption
+    );
+
     // 2. CONCEPT: Direct Lamport Manipulation
     // Since this program OWNS the vault PDA, we can modify its lamports directly.
     // We do not need a CPI to the System Program.
@@ -92,9 +159,146 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     // B. Add to User
     **user.to_account_info().try_borrow_mut_lamports()? += amount;
 
-    msg!("Withdrawn {} lamports from vault", amount);
+    vault.total_withdrawn = vault.total_withdrawn
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.last_activity = Clock::get()?.unix_timestamp;
+
+    msg!("Withdrawn {} lamports from vault. Net deposits: {}", amount, vault.net_deposits());
+    emit!(Withdrawn {
+        owner: vault.owner,
+        vault: vault.key(),
+        amount,
+        vault_balance: vault.to_account_info().lamports(),
+        timestamp: vault.last_activity,
+    });
     Ok(())
 }
+
+    // INSTRUCTION 4: Close Vault
+    // Deletes the vault PDA and returns everything it holds (balance + rent)
+    // to the owner. The `close` constraint on CloseVault does the actual work.
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+
+        // Lamports are moved after the handler returns, so this is the full refund
+        let returned = vault.to_account_info().lamports();
+
+        msg!("Closing vault. Returning {} lamports to the owner", returned);
+        emit!(VaultClosed {
+            owner: vault.owner,
+            vault: vault.key(),
+            lamports_returned: returned,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // INSTRUCTION 5: Propose Owner
+    // Step 1 of an ownership transfer: the current owner nominates a new key.
+    // Nothing changes hands yet; pass None to cancel a pending proposal.
+    pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Option<Pubkey>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.pending_owner = new_owner;
+
+        match new_owner {
+            Some(key) => msg!("Proposed new owner {:?} for vault {}", key, vault.id),
+            None => msg!("Ownership proposal cancelled for vault {}", vault.id),
+        }
+        emit!(OwnershipProposed {
+            owner: vault.owner,
+            vault: vault.key(),
+            pending_owner: new_owner,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // INSTRUCTION 6: Accept Owner
+    // Step 2: the proposed key signs to take over the vault.
+    // CONCEPT: Two-step Transfer
+    // Requiring the new owner's signature proves they control the key,
+    // so a typo in propose_owner can never hand the vault to a dead address.
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let previous_owner = vault.owner;
+
+        vault.owner = ctx.accounts.new_owner.key();
+        vault.pending_owner = None;
+        vault.last_activity = Clock::get()?.unix_timestamp;
+
+        msg!("Vault {} transferred from {:?} to {:?}", vault.id, previous_owner, vault.owner);
+        emit!(OwnershipTransferred {
+            previous_owner,
+            new_owner: vault.owner,
+            vault: vault.key(),
+            timestamp: vault.last_activity,
+        });
+        Ok(())
+    }
+
+    // INSTRUCTION 7: Deposit For
+    // Any payer funds someone else's vault (payroll, gifts).
+    // The `owner` argument is checked against the vault, so a client can't
+    // send funds to the wrong vault by mixing up addresses.
+    pub fn deposit_for(ctx: Context<DepositFor>, owner: Pubkey, amount: u64) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        // Same System Program CPI as deposit, just from the payer's wallet
+        let accounts = Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            accounts,
+        );
+        transfer(cpi_context, amount)?;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.total_deposited = vault.total_deposited
+            .checked_add(amount)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        vault.last_activity = Clock::get()?.unix_timestamp;
+
+        msg!(
+            "{:?} deposited {} lamports to vault {} of {:?}",
+            ctx.accounts.payer.key(),
+            amount,
+            vault.id,
+            owner
+        );
+
+        // The funder is only recorded in the event, not in the vault account
+        emit!(Deposited {
+            owner: vault.owner,
+            vault: vault.key(),
+            funder: ctx.accounts.payer.key(),
+            amount,
+            vault_balance: vault.to_account_info().lamports(),
+            timestamp: vault.last_activity,
+        });
+        Ok(())
+    }
+}
+
+// ACCOUNTS STRUCT 0: Initialize Counter
+#[derive(Accounts)]
+pub struct InitializeCounter<'info> {
+    // One counter per owner: seeds = ["vault_counter", owner]
+    #[account(
+        init,
+        payer = user,
+        space = 8 + VaultCounter::INIT_SPACE,
+        seeds = [b"vault_counter", user.key().as_ref()],
+        bump
+    )]
+    pub counter: Account<'info, VaultCounter>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 // ACCOUNTS STRUCT 1: Initialize
@@ -102,6 +306,14 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
 // This macro generates code to deserialize and validate accounts
 #[derive(Accounts)]
 pub struct Initialize<'info> {
+    // The owner's counter decides the new vault's id
+    #[account(
+        mut,
+        seeds = [b"vault_counter", user.key().as_ref()],
+        bump = counter.bump,
+    )]
+    pub counter: Account<'info, VaultCounter>,
+
     // CONCEPT: #[account(init)] - https://www.anchor-lang.com/docs/the-accounts-struct#init
     // Creates and initializes a new account
     // - init: creates the account
@@ -112,7 +324,7 @@ pub struct Initialize<'info> {
         init,
         payer = user,
         space = 8 + Vault::INIT_SPACE,
-        seeds = [b"vault", user.key().as_ref()],
+        seeds = [b"vault", user.key().as_ref(), &counter.next_vault_id.to_le_bytes()],
         bump
     )]
     pub vault: Account<'info, Vault>,
@@ -132,10 +344,12 @@ pub struct Initialize<'info> {
 pub struct Deposit<'info> {
     // CONCEPT: PDA Validation - https://www.anchor-lang.com/docs/pdas
     // seeds and bump constraints verify this is the correct PDA
+    // The seeds use the creator, so the owner check is a separate constraint
     #[account(
         mut,
-        seeds = [b"vault", user.key().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), &vault.id.to_le_bytes()],
         bump = vault.bump,
+        constraint = vault.owner == user.key() @ VaultError::Unauthorized
     )]
     pub vault: Account<'info, Vault>,
 
@@ -150,10 +364,10 @@ pub struct Deposit<'info> {
 pub struct Withdraw<'info> {
     #[account(
         mut,
-        seeds = [b"vault", user.key().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), &vault.id.to_le_bytes()],
         bump = vault.bump,
         // This ensures the person signing IS the person listed as owner in the vault data
-        constraint = vault.owner == user.key() 
+        constraint = vault.owner == user.key() @ VaultError::Unauthorized
     )]
     pub vault: Account<'info, Vault>,
 
@@ -163,6 +377,76 @@ pub struct Withdraw<'info> {
     pub system_program: Program<'info, System>,
 }
 
+// ACCOUNTS STRUCT 4: Close Vault
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    // CONCEPT: close constraint - https://www.anchor-lang.com/docs/references/account-constraints
+    // After the instruction, Anchor sends ALL of the vault's lamports to `user`
+    // and wipes the account. Its id is never handed out again by the counter.
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.id.to_le_bytes()],
+        bump = vault.bump,
+        constraint = vault.owner == user.key() @ VaultError::Unauthorized,
+        close = user
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+// ACCOUNTS STRUCT 5: Propose Owner
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    // Only the current owner can nominate a successor
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.id.to_le_bytes()],
+        bump = vault.bump,
+        constraint = vault.owner == user.key() @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub user: Signer<'info>,
+}
+
+// ACCOUNTS STRUCT 6: Accept Owner
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    // Only the key named in pending_owner can complete the transfer
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.id.to_le_bytes()],
+        bump = vault.bump,
+        constraint = vault.pending_owner == Some(new_owner.key()) @ VaultError::NotPendingOwner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub new_owner: Signer<'info>,
+}
+
+// ACCOUNTS STRUCT 7: Deposit For
+// CONCEPT: #[instruction] - https://www.anchor-lang.com/docs/references/account-constraints
+// Makes the instruction arguments visible to the constraints below
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct DepositFor<'info> {
+    // No signer check on the owner: anyone may add funds, never take them
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.id.to_le_bytes()],
+        bump = vault.bump,
+        constraint = vault.owner == owner @ VaultError::VaultOwnerMismatch
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 // CONCEPT: #[account] macro - https://www.anchor-lang.com/docs/the-accounts-struct
 // Marks a struct as an Anchor account type
 // CONCEPT: InitSpace - https://www.anchor-lang.com/docs/space
@@ -170,9 +454,86 @@ pub struct Withdraw<'info> {
 #[account]
 #[derive(InitSpace)]
 pub struct Vault {
-    pub owner: Pubkey,  // 32 bytes
-    pub bump: u8,       // 1 byte
-    // Total: 33 bytes + 8 byte discriminator = 41 bytes
+    pub owner: Pubkey,          // 32 bytes
+    pub bump: u8,               // 1 byte
+    pub id: u64,                // 8 bytes - PDA seed, from the owner's counter
+    #[max_len(32)]
+    pub label: Option<String>,  // 1 + 4 + 32 bytes - optional name, e.g. "Savings"
+    pub total_deposited: u64,   // 8 bytes - lamports ever deposited (rent excluded)
+    pub total_withdrawn: u64,   // 8 bytes - lamports ever withdrawn
+    pub last_activity: i64,     // 8 bytes - unix timestamp of the last deposit/withdraw/transfer
+    pub creator: Pubkey,        // 32 bytes - PDA seed; the first owner, never changes
+    pub pending_owner: Option<Pubkey>, // 1 + 32 bytes - proposed new owner, if any
+    // Total: 167 bytes + 8 byte discriminator = 175 bytes
+}
+
+impl Vault {
+    // Net user funds in the vault: deposits minus withdrawals (never includes rent)
+    pub fn net_deposits(&self) -> u64 {
+        self.total_deposited.saturating_sub(self.total_withdrawn)
+    }
+}
+
+// CONCEPT: Per-owner Counter
+// Hands out vault ids so clients never have to guess seeds:
+// fetch next_vault_id, derive ["vault", owner, id], then call initialize
+// The seeds keep the CREATOR's key even after an ownership transfer
+#[account]
+#[derive(InitSpace)]
+pub struct VaultCounter {
+    pub owner: Pubkey,          // 32 bytes
+    pub bump: u8,               // 1 byte
+    pub next_vault_id: u64,     // 8 bytes - id for the next vault
+    // Total: 41 bytes + 8 byte discriminator = 49 bytes
+}
+
+// Maximum label length in bytes (must match #[max_len] on Vault.label)
+pub const MAX_LABEL_LEN: usize = 32;
+
+// CONCEPT: #[event] macro - https://www.anchor-lang.com/docs/features/events
+// Events are structured logs: unlike msg! strings, clients get typed fields
+// (via program.addEventListener or the IDL) instead of text to parse
+#[event]
+pub struct Deposited {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,     // who paid: the owner, or anyone via deposit_for
+    pub amount: u64,
+    pub vault_balance: u64, // vault lamports after the deposit
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Withdrawn {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub vault_balance: u64, // vault lamports after the withdrawal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub lamports_returned: u64, // balance + rent sent to the owner
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipProposed {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub pending_owner: Option<Pubkey>, // None = proposal cancelled
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
 }
 
 // CONCEPT: Custom Errors - https://www.anchor-lang.com/docs/errors
@@ -183,4 +544,16 @@ pub enum VaultError {
     InvalidAmount,
     #[msg("Insufficient funds in vault")]
     InsufficientFunds,
+    #[msg("Withdrawal would leave the vault below its rent-exempt minimum - use close_vault to withdraw everything")]
+    BelowRentExemption,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Vault label is too long (max 32 bytes)")]
+    LabelTooLong,
+    #[msg("Signer is not the proposed new owner of this vault")]
+    NotPendingOwner,
+    #[msg("Vault is not owned by the given owner")]
+    VaultOwnerMismatch,
+    #[msg("Signer is not the owner of this vault")]
+    Unauthorized,
 }
@@ -15,12 +15,22 @@ describe("vault", () => {
   // This prevents "Account already in use" errors on Devnet.
   const user = anchor.web3.Keypair.generate();
 
-  // We need to find the PDA for the vault before we can use it
-  const [vaultPda, vaultBump] = anchor.web3.PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), user.publicKey.toBuffer()],
+  // The owner's counter hands out vault ids: seeds = ["vault_counter", owner]
+  const [counterPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_counter"), user.publicKey.toBuffer()],
     program.programId
   );
 
+  // Each vault's PDA: seeds = ["vault", owner, id as u64 little-endian]
+  const findVaultPda = (id: number) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), user.publicKey.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+  // We need to find the PDA for the vault before we can use it
+  const [vaultPda, vaultBump] = findVaultPda(0);
+
   // 3. AIRDROP LOGIC (With Retry)
   before(async () => {
     console.log(`Preparing test for new user: ${user.publicKey.toBase58()}`);
@@ -47,9 +57,21 @@ describe("vault", () => {
   });
 
   it("Is initialized!", async () => {
+    // One-time setup per owner
     await program.methods
-      .initialize()
+      .initializeCounter()
       .accounts({
+        counter: counterPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    await program.methods
+      .initialize("Savings")
+      .accounts({
+        counter: counterPda,
         vault: vaultPda,
         user: user.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -61,6 +83,10 @@ describe("vault", () => {
 
     assert.ok(vaultAccount.owner.equals(user.publicKey), "Owner matches");
     assert.ok(vaultAccount.bump === vaultBump, "Bump matches");
+    assert.equal(vaultAccount.id.toNumber(), 0, "First vault gets id 0");
+    assert.equal(vaultAccount.label, "Savings", "Label stored");
+    assert.equal(vaultAccount.totalDeposited.toNumber(), 0, "No deposits yet");
+    assert.equal(vaultAccount.totalWithdrawn.toNumber(), 0, "No withdrawals yet");
     console.log("Vault Initialized at:", vaultPda.toBase58());
   });
 
@@ -80,6 +106,11 @@ describe("vault", () => {
 
     const postBalance = await provider.connection.getBalance(vaultPda);
     assert.equal(postBalance, preBalance + amount.toNumber(), "Vault balance incremented by 1 SOL");
+
+    // The account tracks user funds separately from the rent deposit
+    const vaultAccount = await program.account.vault.fetch(vaultPda);
+    assert.equal(vaultAccount.totalDeposited.toNumber(), amount.toNumber(), "total_deposited tracks the deposit");
+    assert.isAbove(vaultAccount.lastActivity.toNumber(), 0, "last_activity recorded");
   });
 
   it("Withdraws SOL", async () => {
@@ -99,6 +130,44 @@ describe("vault", () => {
 
     const postBalance = await provider.connection.getBalance(vaultPda);
     assert.equal(postBalance, preBalance - amount.toNumber(), "Vault balance decremented by 0.5 SOL");
+
+    const vaultAccount = await program.account.vault.fetch(vaultPda);
+    assert.equal(vaultAccount.totalWithdrawn.toNumber(), amount.toNumber(), "total_withdrawn tracks the withdrawal");
+    assert.equal(
+      vaultAccount.totalDeposited.toNumber() - vaultAccount.totalWithdrawn.toNumber(),
+      0.5 * anchor.web3.LAMPORTS_PER_SOL,
+      "Net deposits exclude rent"
+    );
+  });
+
+  it("Emits a Deposited event", async () => {
+    const amount = new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL);
+
+    // CONCEPT: Event Listeners
+    // The client decodes #[event] structs from the transaction logs for us
+    let listener: number;
+    const event = new Promise<any>((resolve) => {
+      listener = program.addEventListener("deposited", (e) => resolve(e));
+    });
+
+    await program.methods
+      .deposit(amount)
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const deposited = await event;
+    await program.removeEventListener(listener);
+
+    const balance = await provider.connection.getBalance(vaultPda);
+    assert.ok(deposited.owner.equals(user.publicKey), "Event owner matches");
+    assert.ok(deposited.vault.equals(vaultPda), "Event vault matches");
+    assert.equal(deposited.amount.toNumber(), amount.toNumber(), "Event amount matches");
+    assert.equal(deposited.vaultBalance.toNumber(), balance, "Event carries the new balance");
   });
 
   it("Fails to withdraw more than available", async () => {
@@ -121,4 +190,246 @@ describe("vault", () => {
       assert.include(error.message, "InsufficientFunds");
     }
   });
+
+  it("Fails to withdraw the rent-exempt reserve", async () => {
+    // Everything the vault holds, rent deposit included
+    const vaultLamports = await provider.connection.getBalance(vaultPda);
+
+    try {
+      await program.methods
+        .withdraw(new anchor.BN(vaultLamports))
+        .accounts({
+          vault: vaultPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      assert.fail("Should have failed: the vault must stay rent-exempt");
+    } catch (error) {
+      assert.include(error.message, "BelowRentExemption");
+    }
+  });
+
+  it("Withdraws everything above the rent-exempt minimum", async () => {
+    const vaultLamports = await provider.connection.getBalance(vaultPda);
+    const accountInfo = await provider.connection.getAccountInfo(vaultPda);
+    const rentMinimum = await provider.connection.getMinimumBalanceForRentExemption(
+      accountInfo.data.length
+    );
+
+    await program.methods
+      .withdraw(new anchor.BN(vaultLamports - rentMinimum))
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const postBalance = await provider.connection.getBalance(vaultPda);
+    assert.equal(postBalance, rentMinimum, "Only the rent reserve is left");
+  });
+
+  it("Closes the vault and refunds everything to the owner", async () => {
+    const vaultLamports = await provider.connection.getBalance(vaultPda);
+    const preUserBalance = await provider.connection.getBalance(user.publicKey);
+
+    await program.methods
+      .closeVault()
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+
+    // The account is gone...
+    const closed = await program.account.vault.fetchNullable(vaultPda);
+    assert.isNull(closed, "Vault account closed");
+
+    // ...and its balance + rent went to the owner (minus the tx fee)
+    const postUserBalance = await provider.connection.getBalance(user.publicKey);
+    assert.isAbove(postUserBalance, preUserBalance + vaultLamports - 10_000, "Owner refunded");
+
+  });
+
+  it("Creates more vaults with fresh ids from the counter", async () => {
+    // Ids are never reused, even though vault 0 was closed
+    const counter = await program.account.vaultCounter.fetch(counterPda);
+    const nextId = counter.nextVaultId.toNumber();
+    assert.equal(nextId, 1, "Counter moved past the first vault");
+
+    const [nextVaultPda] = findVaultPda(nextId);
+    await program.methods
+      .initialize(null) // the label is optional
+      .accounts({
+        counter: counterPda,
+        vault: nextVaultPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const vaultAccount = await program.account.vault.fetch(nextVaultPda);
+    assert.equal(vaultAccount.id.toNumber(), nextId, "Vault id matches its seed");
+    assert.isNull(vaultAccount.label, "No label");
+  });
+
+  it("Fails to create a vault with a label longer than 32 bytes", async () => {
+    const counter = await program.account.vaultCounter.fetch(counterPda);
+    const [nextVaultPda] = findVaultPda(counter.nextVaultId.toNumber());
+
+    try {
+      await program.methods
+        .initialize("x".repeat(33))
+        .accounts({
+          counter: counterPda,
+          vault: nextVaultPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      assert.fail("Should have failed due to the label length");
+    } catch (error) {
+      assert.include(error.message, "LabelTooLong");
+    }
+  });
+
+  it("Transfers a vault to a new owner in two steps", async () => {
+    // Vault 1 from the counter test; the PDA keeps the creator's key in its seeds
+    const [vault1Pda] = findVaultPda(1);
+    const newOwner = anchor.web3.Keypair.generate();
+    const amount = new anchor.BN(100_000_000);
+
+    await program.methods
+      .deposit(amount)
+      .accounts({
+        vault: vault1Pda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    // Step 1: the current owner proposes the new key
+    await program.methods
+      .proposeOwner(newOwner.publicKey)
+      .accounts({ vault: vault1Pda, user: user.publicKey })
+      .signers([user])
+      .rpc();
+
+    let vaultAccount = await program.account.vault.fetch(vault1Pda);
+    assert.ok(vaultAccount.pendingOwner.equals(newOwner.publicKey), "Proposal recorded");
+    assert.ok(vaultAccount.owner.equals(user.publicKey), "Owner unchanged until accepted");
+
+    // Only the proposed key can accept
+    try {
+      await program.methods
+        .acceptOwner()
+        .accounts({ vault: vault1Pda, newOwner: user.publicKey })
+        .signers([user])
+        .rpc();
+      assert.fail("Should have failed: signer is not the pending owner");
+    } catch (error) {
+      assert.include(error.message, "NotPendingOwner");
+    }
+
+    // Step 2: the new owner accepts
+    await program.methods
+      .acceptOwner()
+      .accounts({ vault: vault1Pda, newOwner: newOwner.publicKey })
+      .signers([newOwner])
+      .rpc();
+
+    vaultAccount = await program.account.vault.fetch(vault1Pda);
+    assert.ok(vaultAccount.owner.equals(newOwner.publicKey), "Ownership transferred");
+    assert.ok(vaultAccount.creator.equals(user.publicKey), "Seed key unchanged");
+    assert.isNull(vaultAccount.pendingOwner, "Proposal cleared");
+
+    // The old owner lost access...
+    try {
+      await program.methods
+        .withdraw(amount)
+        .accounts({
+          vault: vault1Pda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Old owner should not be able to withdraw");
+    } catch (error) {
+      assert.include(error.message, "Unauthorized");
+    }
+
+    // ...and the new owner can withdraw from the same address
+    const preBalance = await provider.connection.getBalance(newOwner.publicKey);
+    await program.methods
+      .withdraw(amount)
+      .accounts({
+        vault: vault1Pda,
+        user: newOwner.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([newOwner])
+      .rpc();
+    const postBalance = await provider.connection.getBalance(newOwner.publicKey);
+    assert.equal(postBalance, preBalance + amount.toNumber(), "New owner withdrew");
+  });
+
+  it("Lets anyone deposit into someone else's vault", async () => {
+    // Vault 1 now belongs to the new owner; the original user pays into it
+    const [vault1Pda] = findVaultPda(1);
+    const amount = new anchor.BN(50_000_000);
+    const before = await program.account.vault.fetch(vault1Pda);
+
+    // The owner argument must match the vault
+    try {
+      await program.methods
+        .depositFor(user.publicKey, amount)
+        .accounts({
+          vault: vault1Pda,
+          payer: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Should have failed: wrong owner");
+    } catch (error) {
+      assert.include(error.message, "VaultOwnerMismatch");
+    }
+
+    let listener: number;
+    const event = new Promise<any>((resolve) => {
+      listener = program.addEventListener("deposited", (e) => resolve(e));
+    });
+
+    await program.methods
+      .depositFor(before.owner, amount)
+      .accounts({
+        vault: vault1Pda,
+        payer: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const deposited = await event;
+    await program.removeEventListener(listener);
+
+    const after = await program.account.vault.fetch(vault1Pda);
+    assert.equal(
+      after.totalDeposited.toNumber(),
+      before.totalDeposited.toNumber() + amount.toNumber(),
+      "total_deposited includes the gift"
+    );
+    assert.ok(deposited.owner.equals(before.owner), "Credited to the vault owner");
+    assert.ok(deposited.funder.equals(user.publicKey), "Event records the funder");
+  });
 });
@@ -2,24 +2,85 @@
 // Reference: Rust modules - https://doc.rust-lang.org/book/ch07-02-defining-modules-to-control-scope-and-privacy.html
 // Separating constants makes the code more maintainable and easier to modify
 
-// STAKING PARAMETERS
+// STAKING PARAMETERS (DEFAULTS)
+// These seed the on-chain StakingConfig account in initialize_config.
+// At runtime, the instructions read the config account, which the admin
+// can change with update_config - no redeploy needed.
 
 // Minimum time (in seconds) that funds must be staked to earn rewards
+// Acts as a vesting cliff: unstaking earlier earns nothing,
+// unstaking later earns rewards for the FULL duration
 // 60 seconds = 1 minute (easy for testing/demo)
 // In production, this might be days or weeks
 pub const MIN_STAKE_DURATION: i64 = 60;
 
-// Reward rate: 10% of staked amount
+// Annual reward rate (APR): 10% of staked amount per year
 // Expressed as basis points (1000 = 10%, 10000 = 100%)
 // This avoids floating point math which isn't available in Solana programs
-pub const REWARD_RATE_BASIS_POINTS: u64 = 1000; // 10%
+pub const REWARD_RATE_BASIS_POINTS: u64 = 1000; // 10% APR
+
+// Tip paid to whoever cranks `compound`, as a share of the compounded reward
+// The tip comes from the reward pool ON TOP of the reward, so the staker
+// never pays for being compounded. 0 disables tips.
+pub const COMPOUND_TIP_BASIS_POINTS: u64 = 50; // 0.5% of the reward
+
+// Cooldown (in seconds) between request_unstake and complete_unstake
+// Unstaked principal sits in vault.pending_unstake until it passes
+// 30 seconds = easy for testing/demo; real networks use days
+pub const UNBONDING_PERIOD: i64 = 30;
+
+// Share of the released principal lost when unstaking before the
+// min_stake_duration cliff. Optional: 0 (the default) keeps the old
+// behavior, where leaving early only forfeits the rewards.
+// Like the lock penalty below, it is moved into the reward pool.
+pub const EARLY_UNSTAKE_PENALTY_BASIS_POINTS: u64 = 0;
+
+// Time lock (in seconds) on withdrawals. When above 0, funds can only leave
+// through queue_withdraw + execute_withdraw, so a stolen key can't drain the
+// vault before the owner notices. 0 (the default) keeps instant withdraw.
+pub const WITHDRAWAL_DELAY: i64 = 0;
+
+// LOCK TIERS
+// Longer locks earn a reward multiplier on top of the APR (basis points, 10000 = 1x)
+// Lock durations are in seconds. They stay fixed: they are what the LockTier
+// names promise, and every open position already stored its own lock_end.
+pub const LOCK_DURATION_ONE_DAY: i64 = 24 * 60 * 60;
+pub const LOCK_DURATION_SEVEN_DAYS: i64 = 7 * 24 * 60 * 60;
+pub const LOCK_DURATION_THIRTY_DAYS: i64 = 30 * 24 * 60 * 60;
+
+// No lock always earns exactly the base APR
+pub const LOCK_MULTIPLIER_NONE: u64 = 10000;        // 1x
+
+// Defaults for the config's lock multipliers (tunable with update_config)
+pub const LOCK_MULTIPLIER_ONE_DAY: u64 = 11000;     // 1.1x
+pub const LOCK_MULTIPLIER_SEVEN_DAYS: u64 = 12500;  // 1.25x
+pub const LOCK_MULTIPLIER_THIRTY_DAYS: u64 = 15000; // 1.5x
+
+// Default share of the released principal lost when leaving a lock early
+// The penalty goes to the reward pool, funding the stakers who stayed
+pub const EARLY_EXIT_PENALTY_BASIS_POINTS: u64 = 1000; // 10%
+
+// STAKE POOL
+// reward_per_share is stored multiplied by this factor, so tiny per-lamport
+// rewards (emission / total_staked) don't round down to zero
+pub const REWARD_PER_SHARE_PRECISION: u128 = 1_000_000_000_000; // 1e12
+
+// MULTISIG
+// Upper bound on a multisig's signer set. Signer lists are stored on-chain,
+// so their size must be fixed when the account is created (#[max_len])
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
 
 // Basis points divisor (10000 = 100%)
-// Used to calculate: reward = (staked_amount * REWARD_RATE_BASIS_POINTS) / BASIS_POINTS_DIVISOR
+// Not configurable: this defines what a basis point IS
 pub const BASIS_POINTS_DIVISOR: u64 = 10000;
 
+// Seconds in a (365-day) year - the period the APR applies to
+// Used to calculate:
+// reward = (staked_amount * reward_rate_basis_points * duration) / (BASIS_POINTS_DIVISOR * SECONDS_PER_YEAR)
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+
 // CONCEPT: Fixed-Point Math
 // Solana programs can't use floating point (f32, f64) due to non-determinism
 // Instead, we use integers with a scale factor (basis points)
 // Example: 10% = 1000 basis points, 1% = 100 basis points
-// Calculation: reward = (amount * 1000) / 10000 = amount * 0.10
+// Calculation for one full year: reward = (amount * 1000) / 10000 = amount * 0.10
@@ -15,10 +15,7 @@ pub enum VaultError {
     InsufficientFunds,
 
     // NEW ERRORS (for Day 2 - Staking)
-    #[msg("Cannot stake: funds are already staked")]
-    AlreadyStaked,
-
-    #[msg("Cannot unstake: no funds are currently staked")]
+    #[msg("Cannot unstake: this position has no funds staked")]
     NotStaked,
 
     #[msg("Insufficient unstaked balance to stake this amount")]
@@ -30,8 +27,122 @@ pub enum VaultError {
     #[msg("Arithmetic overflow occurred")]
     ArithmeticOverflow,
 
-    #[msg("Cannot withdraw: funds are currently staked")]
+    #[msg("Funds are currently staked - unstake them first")]
     FundsStaked,
+
+    // AUTHORITY ERRORS
+    #[msg("Signer is not the vault owner or its authorized delegate")]
+    Unauthorized,
+
+    // CONFIG ERRORS
+    #[msg("Invalid staking config: negative duration, tip or penalty above 100%, or lock multipliers below 1x or out of order")]
+    InvalidConfig,
+
+    // POSITION ERRORS
+    #[msg("Cannot unstake more than is staked in this position")]
+    InsufficientStakedBalance,
+
+    // REWARD ERRORS
+    #[msg("No rewards have accrued since the last claim")]
+    NoRewardsToClaim,
+
+    #[msg("Reward pool cannot cover this claim right now")]
+    InsufficientRewardPool,
+
+    // COMPOUNDING ERRORS
+    #[msg("Auto-compounding is not enabled for this vault")]
+    AutoCompoundDisabled,
+
+    // LOCK ERRORS
+    #[msg("Position is still locked - wait for lock_end or accept the early-exit penalty to unstake")]
+    LockPeriodActive,
+
+    // UNBONDING ERRORS
+    #[msg("No unstaked funds are waiting to be completed")]
+    NoPendingUnstake,
+
+    #[msg("Unbonding cooldown has not finished yet")]
+    UnbondingPeriodActive,
+
+    // CLOSE ERRORS
+    #[msg("Unstaked funds are still pending - complete the unstake first")]
+    UnstakePending,
+
+    // OWNERSHIP ERRORS
+    #[msg("Signer is not the proposed new owner of this vault")]
+    NotPendingOwner,
+
+    // DELEGATION ERRORS
+    #[msg("Invalid delegation: limit and period must be positive and expiry in the future")]
+    InvalidDelegation,
+
+    #[msg("Delegation has expired")]
+    DelegationExpired,
+
+    #[msg("Withdrawal exceeds the delegate's remaining allowance")]
+    DelegationLimitExceeded,
+
+    // MULTISIG ERRORS
+    #[msg("Invalid multisig: need 1-10 unique signers and a threshold between 1 and the signer count")]
+    InvalidMultisigConfig,
+
+    #[msg("Signer is not a member of this multisig")]
+    NotMultisigSigner,
+
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal does not have enough approvals yet")]
+    ThresholdNotMet,
+
+    // WITHDRAWAL QUEUE ERRORS
+    #[msg("Withdrawals are time-locked - use queue_withdraw and execute_withdraw")]
+    WithdrawalDelayActive,
+
+    #[msg("No withdrawal is queued for this vault")]
+    NoQueuedWithdrawal,
+
+    #[msg("Queued withdrawal is still time-locked")]
+    WithdrawalLocked,
+
+    #[msg("Delegation is not active until the withdrawal delay has passed")]
+    DelegationNotActive,
+
+    // DEAD-MAN'S SWITCH ERRORS
+    #[msg("Inactivity window must be positive when a beneficiary is set")]
+    InvalidInactivityWindow,
+
+    #[msg("Signer is not the vault's beneficiary")]
+    NotBeneficiary,
+
+    #[msg("Owner was active within the inactivity window")]
+    OwnerStillActive,
+
+    // DEPOSIT FOR ERRORS
+    #[msg("Vault is not owned by the given owner")]
+    VaultOwnerMismatch,
+
+    // VAULT TRANSFER ERRORS
+    #[msg("Source and destination vault must differ")]
+    SameVault,
+
+    #[msg("Revoke all delegations before closing the vault")]
+    OpenDelegations,
+
+    // ADMIN ERRORS
+    #[msg("Only the program's upgrade authority can initialize the config and pools")]
+    NotUpgradeAuthority,
+
+    // STAKE POOL ERRORS
+    #[msg("The stake pool is active - pass it as the stake_pool account")]
+    StakePoolRequired,
+
+    // OWNERSHIP ERRORS
+    #[msg("Ownership transfer is still time-locked - wait for the unlock time")]
+    OwnershipTransferLocked,
 }
 
 // CONCEPT: Error Messages Best Practices
@@ -44,7 +155,7 @@ pub enum VaultError {
 // Anchor automatically assigns error codes starting from 6000:
 // - InvalidAmount = 6000
 // - InsufficientFunds = 6001
-// - AlreadyStaked = 6002
+// - NotStaked = 6002
 // - etc.
 //
 // These codes appear in transaction logs and help with debugging
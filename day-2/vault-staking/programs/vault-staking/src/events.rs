@@ -0,0 +1,361 @@
+use anchor_lang::prelude::*;
+use crate::state::LockTier;
+
+// CONCEPT: Anchor Events
+// Reference: https://www.anchor-lang.com/docs/features/events
+// msg! strings are for humans and are brittle to parse.
+// `emit!` writes a #[event] struct to the transaction logs as base64-encoded
+// Borsh data, and the IDL describes its layout, so indexers and clients
+// (program.addEventListener, EventParser) get typed fields instead of text.
+//
+// Every event carries the vault owner, the amounts involved, the timestamp
+// and the vault balances AFTER the change, so an indexer never needs to
+// fetch the account to know the new state.
+
+// VAULT EVENTS
+
+#[event]
+pub struct VaultInitialized {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Deposited {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,          // who paid: the owner, or anyone via deposit_for
+    pub amount: u64,
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Withdrawn {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,       // owner or delegate that signed
+    pub amount: u64,
+    pub remaining_allowance: Option<u64>, // Some when drawn from a spending-limited Delegation
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalQueued {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,       // owner or delegate that signed
+    pub amount: u64,             // added by this call
+    pub queued_withdrawal: u64,  // whole queued bucket
+    pub unlock_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalCancelled {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,             // released back to the available balance
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InternalTransfer {
+    pub from_owner: Pubkey,
+    pub from_vault: Pubkey,
+    pub to_owner: Pubkey,
+    pub to_vault: Pubkey,
+    pub authority: Pubkey,       // owner or delegate of the source vault
+    pub amount: u64,
+    pub from_total_deposited: u64,
+    pub to_total_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub lamports_returned: u64,  // balance + rent sent to the owner
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegateUpdated {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub delegate: Option<Pubkey>, // None = revoked
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegationGranted {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub delegate: Pubkey,
+    pub limit_per_period: u64,
+    pub period_duration: i64,
+    pub expires_at: i64,
+    pub active_from: i64,        // usable once the withdrawal delay has passed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegationRevoked {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryUpdated {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub beneficiary: Option<Pubkey>, // None = removed
+    pub inactivity_window: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HeartbeatRecorded {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InheritanceClaimed {
+    pub previous_owner: Pubkey,
+    pub beneficiary: Pubkey,     // the new owner
+    pub vault: Pubkey,
+    pub amount: u64,             // unstaked balance paid out right away
+    pub total_deposited: u64,    // still in the vault (staked or unbonding)
+    pub last_owner_activity: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipProposed {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub pending_owner: Option<Pubkey>, // None = proposal cancelled
+    pub unlock_time: i64,              // pending_owner can accept from this time on
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AutoCompoundUpdated {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+// MULTISIG EVENTS
+
+#[event]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub creator: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigDeposited {
+    pub multisig: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalProposed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,           // approvals so far, including this one
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+// STAKING EVENTS
+
+#[event]
+pub struct Staked {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub position_index: u64,
+    pub amount: u64,
+    pub lock_tier: LockTier,
+    pub lock_end: i64,
+    pub staked_amount: u64,      // vault total across positions
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Unstaked {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub position_index: u64,
+    pub amount: u64,
+    pub penalty: u64,            // principal moved to the reward pool
+    pub reward_earned: u64,      // APR reward the released part earned
+    pub reward_shortfall: u64,   // part of reward_earned the pool couldn't pay (lost)
+    pub position_closed: bool,
+    pub staked_amount: u64,
+    pub pending_unstake: u64,
+    pub unbonding_end: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnstakeCompleted {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,             // principal released for withdrawal
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+// Where a reward payout came from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RewardSource {
+    Unstake,   // request_unstake settling the released part
+    Claim,     // claim_rewards
+    Compound,  // compound crank (re-staked right away)
+    StakePool, // stake pool emissions (harvest or automatic settlement)
+}
+
+#[event]
+pub struct RewardPaid {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub position_index: Option<u64>, // None for stake pool rewards (per vault, not per position)
+    pub source: RewardSource,
+    pub amount: u64,
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompoundTipPaid {
+    pub vault: Pubkey,
+    pub caller: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// ADMIN EVENTS
+
+#[event]
+pub struct ConfigInitialized {
+    pub admin: Pubkey,
+    pub min_stake_duration: i64,
+    pub reward_rate_basis_points: u64,
+    pub compound_tip_basis_points: u64,
+    pub unbonding_period: i64,
+    pub early_unstake_penalty_basis_points: u64,
+    pub withdrawal_delay: i64,
+    pub lock_multiplier_one_day_basis_points: u64,
+    pub lock_multiplier_seven_days_basis_points: u64,
+    pub lock_multiplier_thirty_days_basis_points: u64,
+    pub early_exit_penalty_basis_points: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardPoolInitialized {
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakePoolInitialized {
+    pub admin: Pubkey,
+    pub emission_rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardPoolFunded {
+    pub admin: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakePoolFunded {
+    pub admin: Pubkey,
+    pub amount: u64,
+    pub undistributed: u64,
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakePoolRateUpdated {
+    pub admin: Pubkey,
+    pub previous_rate: u64,
+    pub emission_rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub min_stake_duration: i64,
+    pub reward_rate_basis_points: u64,
+    pub compound_tip_basis_points: u64,
+    pub unbonding_period: i64,
+    pub early_unstake_penalty_basis_points: u64,
+    pub withdrawal_delay: i64,
+    pub lock_multiplier_one_day_basis_points: u64,
+    pub lock_multiplier_seven_days_basis_points: u64,
+    pub lock_multiplier_thirty_days_basis_points: u64,
+    pub early_exit_penalty_basis_points: u64,
+    pub timestamp: i64,
+}
+
+// CONCEPT: emit! vs emit_cpi!
+// emit! logs the event with sol_log_data. It's cheap, but logs can be
+// truncated if a transaction logs too much. emit_cpi! instead sends the
+// event as a self-CPI (needs an extra event_authority account), which
+// indexers can read from the instruction data and never gets truncated.
+// These events are small, so plain emit! is enough here.
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::OwnershipTransferred;
+
+// INSTRUCTION: Accept Owner
+// Step 2 of 2: the proposed owner signs to take over the vault.
+// Only `owner` changes - the PDA, its balances and stake positions stay put,
+// because the seeds use vault.creator. No funds have to be migrated.
+
+pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // The current owner can still cancel with propose_owner(None) until now
+    require!(
+        current_time >= vault.owner_transfer_unlock_time,
+        VaultError::OwnershipTransferLocked
+    );
+
+    let previous_owner = vault.owner;
+
+    vault.owner = ctx.accounts.new_owner.key();
+    vault.pending_owner = None;
+    vault.owner_transfer_unlock_time = 0;
+
+    // CONCEPT: Ownership Epochs
+    // Comparing delegation.granted_by with the owner isn't enough: after an
+    // A -> B -> A round trip, A's old grants would match again. Every transfer
+    // bumps the epoch instead, so grants from any earlier ownership stay dead.
+    vault.owner_epoch = vault.owner_epoch
+        .checked_add(1)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    // The delegate and beneficiary were chosen by the previous owner,
+    // so they don't carry over
+    vault.delegate = None;
+    vault.beneficiary = None;
+    vault.inactivity_window = 0;
+    vault.last_owner_activity = current_time;
+
+    msg!("Vault ownership transferred from {:?} to {:?}", previous_owner, vault.owner);
+    emit!(OwnershipTransferred {
+        previous_owner,
+        new_owner: vault.owner,
+        vault: vault.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    // CONCEPT: Two-step Ownership Transfer
+    // The new key must sign to accept, which proves someone controls it.
+    // A one-step transfer to a mistyped address would lock the vault forever.
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.pending_owner == Some(new_owner.key()) @ VaultError::NotPendingOwner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub new_owner: Signer<'info>,
+}
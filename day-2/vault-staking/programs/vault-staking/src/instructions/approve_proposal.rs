@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{MultisigVault, Proposal};
+use crate::errors::VaultError;
+use crate::events::ProposalApproved;
+
+// INSTRUCTION: Approve Proposal
+// Records one signer's approval. Each signer can approve a proposal once.
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let signer = ctx.accounts.signer.key();
+
+    require!(!proposal.executed, VaultError::ProposalAlreadyExecuted);
+    require!(!proposal.approvals.contains(&signer), VaultError::AlreadyApproved);
+
+    // At most one entry per signer, so this never exceeds MAX_MULTISIG_SIGNERS
+    proposal.approvals.push(signer);
+
+    msg!(
+        "Proposal {} approved by {:?} ({} of {} approvals)",
+        proposal.index,
+        signer,
+        proposal.approvals.len(),
+        multisig.threshold
+    );
+    emit!(ProposalApproved {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        signer,
+        approvals: proposal.approvals.len() as u8,
+        threshold: multisig.threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&signer.key()) @ VaultError::NotMultisigSigner
+    )]
+    pub multisig: Account<'info, MultisigVault>,
+
+    // has_one = multisig ties the proposal to this multisig
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub signer: Signer<'info>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::WithdrawalCancelled;
+
+// INSTRUCTION: Cancel Withdraw
+// Drops the queued withdrawal; the amount is available again right away.
+
+pub fn cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(vault.queued_withdrawal > 0, VaultError::NoQueuedWithdrawal);
+
+    let released = vault.queued_withdrawal;
+    vault.queued_withdrawal = 0;
+    vault.withdrawal_unlock_time = 0;
+
+    msg!(
+        "Queued withdrawal of {} lamports cancelled. Available balance: {} lamports",
+        released,
+        vault.get_available_balance()
+    );
+    emit!(WithdrawalCancelled {
+        owner: vault.owner,
+        vault: vault.key(),
+        authority: ctx.accounts.user.key(),
+        amount: released,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub user: Signer<'info>,
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakingConfig};
+use crate::errors::VaultError;
+use crate::events::InheritanceClaimed;
+
+// INSTRUCTION: Claim Inheritance
+// The beneficiary takes over a vault whose owner has been silent for the
+// whole inactivity window. The unstaked balance is paid out right away and
+// ownership passes to the beneficiary, so anything still staked or
+// unbonding can be unstaked and withdrawn by them later.
+
+pub fn claim_inheritance(ctx: Context<ClaimInheritance>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // CONCEPT: Dead-man's Switch
+    // Every owner deposit/withdraw/stake/heartbeat moves last_owner_activity
+    // forward, so the claim only opens after a full window of silence.
+    // The window is never shorter than the withdrawal delay: otherwise a
+    // stolen key could name itself beneficiary and skip the time lock.
+    require!(
+        vault.is_owner_inactive(current_time, ctx.accounts.config.withdrawal_delay),
+        VaultError::OwnerStillActive
+    );
+
+    // A queued withdrawal was the previous owner's - release it to the heir
+    vault.queued_withdrawal = 0;
+    vault.withdrawal_unlock_time = 0;
+
+    // Pay out everything that isn't staked or unbonding,
+    // never touching the rent-exempt minimum
+    let vault_info = vault.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let withdrawable_lamports = vault_info.lamports().saturating_sub(rent_minimum);
+    let amount = vault.get_available_balance().min(withdrawable_lamports);
+
+    if amount > 0 {
+        vault.sub_lamports(amount)?;
+        ctx.accounts.beneficiary.add_lamports(amount)?;
+        vault.total_deposited = vault.total_deposited
+            .checked_sub(amount)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+    }
+
+    // Hand the vault over; everything the previous owner chose is reset
+    let previous_owner = vault.owner;
+    let last_owner_activity = vault.last_owner_activity;
+    vault.owner = ctx.accounts.beneficiary.key();
+    vault.delegate = None;
+    vault.pending_owner = None;
+    vault.owner_transfer_unlock_time = 0;
+    // Retires every Delegation the previous owner granted, like accept_owner
+    vault.owner_epoch = vault.owner_epoch
+        .checked_add(1)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.beneficiary = None;
+    vault.inactivity_window = 0;
+    vault.last_owner_activity = current_time;
+
+    msg!(
+        "Inheritance claimed: {} lamports paid out, {} lamports still in the vault",
+        amount,
+        vault.total_deposited
+    );
+    emit!(InheritanceClaimed {
+        previous_owner,
+        beneficiary: vault.owner,
+        vault: vault.key(),
+        amount,
+        total_deposited: vault.total_deposited,
+        last_owner_activity,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimInheritance<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.beneficiary == Some(beneficiary.key()) @ VaultError::NotBeneficiary
+    )]
+    pub vault: Account<'info, Vault>,
+
+    // Read for the withdrawal delay
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    // Receives the payout and becomes the new owner
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakePosition, RewardPool, StakingConfig};
+use crate::errors::VaultError;
+use crate::rewards::{calculate_reward, pay_reward, reward_pool_available};
+use crate::events::{RewardPaid, RewardSource};
+
+// INSTRUCTION: Claim Rewards
+// Settles the rewards a position has accrued into the vault's available
+// balance WITHOUT unstaking. The principal stays staked and keeps its
+// stake_timestamp; only the accrual checkpoint moves forward.
+
+pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let position = &mut ctx.accounts.stake_position;
+    let config = &ctx.accounts.config;
+
+    require!(position.amount > 0, VaultError::NotStaked);
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    // CONCEPT: Locked Rewards Stay Locked
+    // The lock multiplier is only earned by honouring the lock, so a locked
+    // position cannot claim. Boosted rewards are collected once lock_end passes,
+    // and an early exit forfeits everything accrued since the stake.
+    require!(!position.is_locked(current_time), VaultError::LockPeriodActive);
+
+    // Same cliff as request_unstake: nothing is claimable before the minimum duration
+    require!(
+        position.has_met_min_duration(current_time, config.min_stake_duration),
+        VaultError::MinimumStakeDurationNotMet
+    );
+
+    let accrual_duration = position.get_accrual_duration(current_time);
+    let reward = calculate_reward(
+        position.amount,
+        config.reward_rate_basis_points,
+        position.lock_tier.multiplier_basis_points(config),
+        accrual_duration,
+    )?;
+    require!(reward > 0, VaultError::NoRewardsToClaim);
+
+    // CONCEPT: All-or-Nothing Claim
+    // Unlike request_unstake, claiming is optional, so a short pool fails the claim
+    // instead of paying part and moving the checkpoint past unpaid rewards.
+    // The user can simply claim again once the pool is topped up.
+    require!(
+        reward <= reward_pool_available(reward_pool)?,
+        VaultError::InsufficientRewardPool
+    );
+
+    pay_reward(reward_pool, vault, reward)?;
+
+    // CONCEPT: Accrual Checkpoint
+    // Rewards up to now are settled, so future rewards accrue from here
+    position.last_claim_timestamp = current_time;
+
+    msg!(
+        "Claimed {} lamports from position {} ({}s accrued). Total balance now: {} lamports",
+        reward,
+        position.index,
+        accrual_duration,
+        vault.total_deposited
+    );
+    emit!(RewardPaid {
+        owner: vault.owner,
+        vault: vault.key(),
+        position_index: Some(position.index),
+        source: RewardSource::Claim,
+        amount: reward,
+        total_deposited: vault.total_deposited,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_position", vault.key().as_ref(), &stake_position.index.to_le_bytes()],
+        bump = stake_position.bump,
+        has_one = vault
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    pub user: Signer<'info>,
+}
+
+// CONCEPT: Why Claim Separately?
+// Long-term stakers want their yield without giving up their position:
+// - Withdraw rewards on a schedule (e.g. monthly income)
+// - Re-stake them in a new position (manual compounding)
+//
+// The cliff is still measured from stake_timestamp, so claiming never
+// restarts the minimum duration - only the reward accrual window.
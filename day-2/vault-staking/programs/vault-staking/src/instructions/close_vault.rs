@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakingConfig};
+use crate::errors::VaultError;
+use crate::events::VaultClosed;
+
+// INSTRUCTION: Close Vault
+// Tears down the vault PDA: every lamport it holds (balance + rent) goes back
+// to the owner and the account is deleted, so the same seeds can be reused
+// by a fresh `initialize` later.
+
+pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+
+    // The `close` constraint moves the lamports after this handler returns,
+    // so this is everything the owner is about to receive
+    let returned = vault.to_account_info().lamports();
+
+    msg!(
+        "Closing vault {:?}. Returning {} lamports ({} balance + rent) to the owner",
+        vault.key(),
+        returned,
+        vault.total_deposited
+    );
+    emit!(VaultClosed {
+        owner: vault.owner,
+        vault: vault.key(),
+        lamports_returned: returned,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    // CONCEPT: The `close` Constraint
+    // Reference: https://www.anchor-lang.com/docs/references/account-constraints#account-close-target
+    // At the end of the instruction Anchor moves ALL the account's lamports to
+    // `owner`, zeroes its data and assigns it back to the System Program.
+    //
+    // Owner-only (a delegate can't close), and only once nothing is staked or
+    // unbonding - otherwise closing would skip the stake and cooldown rules.
+    // With no stake there are no open positions and no stake pool rewards owed.
+    // While withdrawals are time-locked, the balance must leave through the
+    // queue first - closing would otherwise be an instant withdraw.
+    // Delegation PDAs outlive the vault (same seeds after a re-initialize),
+    // so every grant has to be revoked first.
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = vault.staked_amount == 0 @ VaultError::FundsStaked,
+        constraint = vault.pending_unstake == 0 @ VaultError::UnstakePending,
+        constraint = vault.open_delegations == 0 @ VaultError::OpenDelegations,
+        constraint = config.withdrawal_delay == 0 || vault.total_deposited == 0 @ VaultError::WithdrawalDelayActive,
+        close = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    // Receives the vault's balance and rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
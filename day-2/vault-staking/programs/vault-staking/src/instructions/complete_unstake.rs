@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::UnstakeCompleted;
+
+// INSTRUCTION: Complete Unstake
+// Phase 2 of 2: once the unbonding cooldown has passed, moves the vault's
+// pending principal back into its available balance so it can be withdrawn
+// or staked again.
+
+pub fn complete_unstake(ctx: Context<CompleteUnstake>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(vault.pending_unstake > 0, VaultError::NoPendingUnstake);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= vault.unbonding_end,
+        VaultError::UnbondingPeriodActive
+    );
+
+    // Pending funds are already counted in total_deposited;
+    // clearing the bucket is all it takes to make them available
+    let released = vault.pending_unstake;
+    vault.pending_unstake = 0;
+
+    msg!(
+        "Unbonding complete: {} lamports released. Available balance: {} lamports",
+        released,
+        vault.get_available_balance()
+    );
+    emit!(UnstakeCompleted {
+        owner: vault.owner,
+        vault: vault.key(),
+        amount: released,
+        total_deposited: vault.total_deposited,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub user: Signer<'info>,
+}
+
+// CONCEPT: Why an Unbonding Period?
+// Real staking systems (Solana stake accounts, Cosmos, Ethereum) don't let
+// stake leave instantly:
+// - The protocol can forecast outflows (pending_unstake is visible on-chain)
+// - Sudden "bank runs" are smoothed out
+// - Misbehaviour can still be penalized while funds are unbonding
+//
+// Note the funds never leave the vault during unbonding. They are only
+// excluded from get_available_balance, so withdraw and stake can't use them.
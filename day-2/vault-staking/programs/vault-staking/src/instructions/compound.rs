@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakePosition, RewardPool, StakePool, StakingConfig};
+use crate::errors::VaultError;
+use crate::constants::*;
+use crate::rewards::{
+    calculate_reward, pay_reward, reward_pool_available,
+    update_stake_pool, enroll_in_stake_pool, settle_pool_reward, sync_reward_debt,
+};
+use crate::events::{RewardPaid, RewardSource, CompoundTipPaid};
+
+// INSTRUCTION: Compound
+// Permissionless "crank": rolls a position's accrued rewards back into the
+// position's staked amount. Only works on vaults that opted in with
+// set_auto_compound. The caller earns a small tip from the reward pool.
+
+pub fn compound(ctx: Context<Compound>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let position = &mut ctx.accounts.stake_position;
+    let config = &ctx.accounts.config;
+
+    require!(position.amount > 0, VaultError::NotStaked);
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    // Like claim_rewards, a locked position cannot collect its boosted rewards early
+    require!(!position.is_locked(current_time), VaultError::LockPeriodActive);
+
+    // Same cliff and accrual window as claim_rewards and request_unstake
+    require!(
+        position.has_met_min_duration(current_time, config.min_stake_duration),
+        VaultError::MinimumStakeDurationNotMet
+    );
+
+    let accrual_duration = position.get_accrual_duration(current_time);
+    let reward = calculate_reward(
+        position.amount,
+        config.reward_rate_basis_points,
+        position.lock_tier.multiplier_basis_points(config),
+        accrual_duration,
+    )?;
+    require!(reward > 0, VaultError::NoRewardsToClaim);
+
+    // CONCEPT: Crank Incentive
+    // Nobody runs a crank for free. The tip is a share of the reward,
+    // paid by the pool on top of it, so the staker is never charged.
+    let tip = (reward as u128)
+        .checked_mul(config.compound_tip_basis_points as u128)
+        .ok_or(VaultError::ArithmeticOverflow)?
+        .checked_div(BASIS_POINTS_DIVISOR as u128)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    let tip = u64::try_from(tip).map_err(|_| VaultError::ArithmeticOverflow)?;
+
+    // All-or-nothing, like claim_rewards: never move the checkpoint past unpaid rewards
+    let total_payout = reward.checked_add(tip).ok_or(VaultError::ArithmeticOverflow)?;
+    require!(
+        total_payout <= reward_pool_available(reward_pool)?,
+        VaultError::InsufficientRewardPool
+    );
+
+    // STEP 1: Pay the reward into the vault...
+    pay_reward(reward_pool, vault, reward)?;
+
+    // STEP 2: ...and immediately stake it in the same position
+    // (stake pool rewards at the old staked amount are settled first)
+    let mut pool_reward = 0;
+    if let Some(stake_pool) = ctx.accounts.stake_pool.as_mut() {
+        update_stake_pool(stake_pool, current_time)?;
+        enroll_in_stake_pool(stake_pool, vault)?;
+        pool_reward = settle_pool_reward(stake_pool, vault)?;
+    }
+
+    position.amount = position.amount
+        .checked_add(reward)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.staked_amount = vault.staked_amount
+        .checked_add(reward)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    if let Some(stake_pool) = ctx.accounts.stake_pool.as_mut() {
+        stake_pool.total_staked = stake_pool.total_staked
+            .checked_add(reward)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        sync_reward_debt(stake_pool, vault)?;
+    }
+    if pool_reward > 0 {
+        emit!(RewardPaid {
+            owner: vault.owner,
+            vault: vault.key(),
+            position_index: None,
+            source: RewardSource::StakePool,
+            amount: pool_reward,
+            total_deposited: vault.total_deposited,
+            timestamp: current_time,
+        });
+    }
+
+    // Rewards up to now are settled; the stake timestamp (cliff) is untouched
+    position.last_claim_timestamp = current_time;
+
+    // STEP 3: Tip the caller
+    if tip > 0 {
+        reward_pool.sub_lamports(tip)?;
+        ctx.accounts.caller.add_lamports(tip)?;
+
+        reward_pool.total_paid = reward_pool.total_paid
+            .checked_add(tip)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+    }
+
+    msg!(
+        "Compounded {} lamports into position {} (now {} staked). Tip: {} lamports",
+        reward,
+        position.index,
+        position.amount,
+        tip
+    );
+    emit!(RewardPaid {
+        owner: vault.owner,
+        vault: vault.key(),
+        position_index: Some(position.index),
+        source: RewardSource::Compound,
+        amount: reward,
+        total_deposited: vault.total_deposited,
+        timestamp: current_time,
+    });
+    if tip > 0 {
+        emit!(CompoundTipPaid {
+            vault: vault.key(),
+            caller: ctx.accounts.caller.key(),
+            amount: tip,
+            timestamp: current_time,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Compound<'info> {
+    // CONCEPT: Permissionless Instruction
+    // No owner/delegate check: the vault's own auto_compound flag is the
+    // permission. Compounding can only ever ADD to the owner's stake.
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.auto_compound @ VaultError::AutoCompoundDisabled
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_position", vault.key().as_ref(), &stake_position.index.to_le_bytes()],
+        bump = stake_position.bump,
+        has_one = vault
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    // Optional stake pool: staking works before it exists, but once it
+    // does every stake change has to settle against it
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+        constraint = stake_pool.is_some() || !config.stake_pool_active @ VaultError::StakePoolRequired
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    // Global emissions accumulator; settled before the stake changes
+    // (None until initialize_stake_pool has run)
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump,
+    )]
+    pub stake_pool: Option<Account<'info, StakePool>>,
+
+    // Anyone can crank; receives the tip
+    #[account(mut)]
+    pub caller: Signer<'info>,
+}
+
+// CONCEPT: Why Is This Safe to Open Up?
+// The crank can't choose amounts or destinations:
+// - The reward comes from the same formula as claim_rewards (rewards.rs)
+// - The reward always lands in the owner's own position
+// - The only lamports leaving the program are the tip, capped by config
+// The worst a caller can do is compound more often than the owner would.
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::MultisigVault;
+use crate::errors::VaultError;
+use crate::constants::MAX_MULTISIG_SIGNERS;
+use crate::events::MultisigCreated;
+
+// INSTRUCTION: Create Multisig
+// Creates an M-of-N multisig vault: `threshold` of the `signers` must
+// approve every withdrawal. The signer set is fixed once created.
+
+pub fn create_multisig(
+    ctx: Context<CreateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    // CONCEPT: Validating the Signer Set
+    // A duplicate key would count twice towards the threshold, and a
+    // threshold above the signer count could never be reached (funds stuck).
+    let mut unique = signers.clone();
+    unique.sort();
+    unique.dedup();
+    require!(
+        !signers.is_empty()
+            && signers.len() <= MAX_MULTISIG_SIGNERS
+            && unique.len() == signers.len()
+            && threshold > 0
+            && threshold as usize <= signers.len(),
+        VaultError::InvalidMultisigConfig
+    );
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.creator = ctx.accounts.creator.key();
+    multisig.bump = ctx.bumps.multisig;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.total_deposited = 0;
+    multisig.next_proposal_index = 0;
+
+    msg!(
+        "Multisig created: {} of {} signers",
+        multisig.threshold,
+        multisig.signers.len()
+    );
+    emit!(MultisigCreated {
+        multisig: multisig.key(),
+        creator: multisig.creator,
+        signers: multisig.signers.clone(),
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + MultisigVault::INIT_SPACE,
+        seeds = [b"multisig", creator.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, MultisigVault>,
+
+    // Pays for the account; only a signer of the multisig if listed in `signers`
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
 use crate::state::Vault;
 use crate::errors::VaultError;
+use crate::events::Deposited;
 
 // INSTRUCTION: Deposit
 // Transfers SOL from user to vault and updates total_deposited
@@ -28,8 +29,21 @@ pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
     vault.total_deposited = vault.total_deposited
         .checked_add(amount)
         .ok_or(VaultError::ArithmeticOverflow)?;
+    let current_time = Clock::get()?.unix_timestamp;
+    vault.record_owner_activity(&ctx.accounts.user.key(), current_time);
 
     msg!("Deposited {} lamports. Total deposited: {}", amount, vault.total_deposited);
+
+    // CONCEPT: Structured Events
+    // The same information as the log line above, but typed (see events.rs)
+    emit!(Deposited {
+        owner: vault.owner,
+        vault: vault.key(),
+        funder: ctx.accounts.user.key(),
+        amount,
+        total_deposited: vault.total_deposited,
+        timestamp: current_time,
+    });
     Ok(())
 }
 
@@ -37,8 +51,10 @@ pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
 pub struct Deposit<'info> {
     #[account(
         mut,
-        seeds = [b"vault", user.key().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref()],
         bump = vault.bump,
+        // Only the owner deposits into their own vault
+        constraint = vault.owner == user.key() @ VaultError::Unauthorized
     )]
     pub vault: Account<'info, Vault>,
 
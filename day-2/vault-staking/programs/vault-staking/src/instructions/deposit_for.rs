@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::Deposited;
+
+// INSTRUCTION: Deposit For
+// Any payer adds SOL to someone else's vault (payroll, gifts).
+// Adding funds is always safe, so the owner doesn't have to sign;
+// the `owner` argument only guards against paying into the wrong vault.
+
+pub fn deposit_for(ctx: Context<DepositFor>, owner: Pubkey, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    // Same System Program CPI as deposit, just from the payer's wallet
+    let accounts = Transfer {
+        from: ctx.accounts.payer.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        accounts,
+    );
+    transfer(cpi_context, amount)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.total_deposited = vault.total_deposited
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    // Only counts for the dead-man's switch when the owner pays in themselves
+    let current_time = Clock::get()?.unix_timestamp;
+    vault.record_owner_activity(&ctx.accounts.payer.key(), current_time);
+
+    msg!(
+        "{:?} deposited {} lamports for {:?}. Total deposited: {}",
+        ctx.accounts.payer.key(),
+        amount,
+        owner,
+        vault.total_deposited
+    );
+    emit!(Deposited {
+        owner: vault.owner,
+        vault: vault.key(),
+        funder: ctx.accounts.payer.key(),
+        amount,
+        total_deposited: vault.total_deposited,
+        timestamp: current_time,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct DepositFor<'info> {
+    // CONCEPT: #[instruction] Arguments
+    // Reference: https://www.anchor-lang.com/docs/references/account-constraints
+    // The constraint compares the vault against the `owner` argument;
+    // the seeds use vault.creator, so this still works after a transfer
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.owner == owner @ VaultError::VaultOwnerMismatch
+    )]
+    pub vault: Account<'info, Vault>,
+
+    // Anyone - pays the deposit
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
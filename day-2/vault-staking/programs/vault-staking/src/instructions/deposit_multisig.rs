@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::MultisigVault;
+use crate::errors::VaultError;
+use crate::events::MultisigDeposited;
+
+// INSTRUCTION: Deposit Multisig
+// Transfers SOL from any wallet into a multisig vault.
+// Paying in is open to anyone; only paying OUT needs the signers.
+
+pub fn deposit_multisig(ctx: Context<DepositMultisig>, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let accounts = Transfer {
+        from: ctx.accounts.depositor.to_account_info(),
+        to: ctx.accounts.multisig.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        accounts,
+    );
+    transfer(cpi_context, amount)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.total_deposited = multisig.total_deposited
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    msg!("Deposited {} lamports into multisig. Total deposited: {}", amount, multisig.total_deposited);
+    emit!(MultisigDeposited {
+        multisig: multisig.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        total_deposited: multisig.total_deposited,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigVault>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::{MultisigVault, Proposal};
+use crate::errors::VaultError;
+use crate::events::ProposalExecuted;
+
+// INSTRUCTION: Execute Proposal
+// Sends the proposed lamports to the recipient once `threshold` signers
+// approved. Any signer of the multisig can trigger it; it runs only once.
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(!proposal.executed, VaultError::ProposalAlreadyExecuted);
+    require!(
+        proposal.approvals.len() >= multisig.threshold as usize,
+        VaultError::ThresholdNotMet
+    );
+
+    // Same balance rules as withdraw: user funds only, never the rent deposit
+    let amount = proposal.amount;
+    require!(multisig.total_deposited >= amount, VaultError::InsufficientFunds);
+    let multisig_info = multisig.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(multisig_info.data_len());
+    let withdrawable_lamports = multisig_info.lamports().saturating_sub(rent_minimum);
+    require!(withdrawable_lamports >= amount, VaultError::InsufficientFunds);
+
+    // The multisig PDA is owned by this program, so we move lamports directly
+    multisig.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+
+    multisig.total_deposited = multisig.total_deposited
+        .checked_sub(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    // CONCEPT: Replay Protection
+    // Marking the proposal executed (instead of closing it) keeps an on-chain
+    // record and makes a second execute fail with ProposalAlreadyExecuted
+    proposal.executed = true;
+
+    msg!(
+        "Proposal {} executed: {} lamports sent to {:?}",
+        proposal.index,
+        amount,
+        proposal.recipient
+    );
+    emit!(ProposalExecuted {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        recipient: proposal.recipient,
+        amount,
+        total_deposited: multisig.total_deposited,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&signer.key()) @ VaultError::NotMultisigSigner
+    )]
+    pub multisig: Account<'info, MultisigVault>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = recipient
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Must be the recipient named in the proposal (has_one above)
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
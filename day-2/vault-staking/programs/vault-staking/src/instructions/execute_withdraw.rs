@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::Withdrawn;
+
+// INSTRUCTION: Execute Withdraw
+// Phase 2 of 2: once the delay has passed, sends the whole queued amount
+// to the signer (the owner or delegate).
+
+pub fn execute_withdraw(ctx: Context<ExecuteWithdraw>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(vault.queued_withdrawal > 0, VaultError::NoQueuedWithdrawal);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= vault.withdrawal_unlock_time,
+        VaultError::WithdrawalLocked
+    );
+    vault.record_owner_activity(&ctx.accounts.user.key(), current_time);
+
+    // The queued amount was reserved out of total_deposited, so only the
+    // rent deposit needs checking, same as in withdraw
+    let amount = vault.queued_withdrawal;
+    let vault_info = vault.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let withdrawable_lamports = vault_info.lamports().saturating_sub(rent_minimum);
+    require!(
+        withdrawable_lamports >= amount,
+        VaultError::InsufficientFunds
+    );
+
+    vault.sub_lamports(amount)?;
+    ctx.accounts.user.add_lamports(amount)?;
+
+    vault.queued_withdrawal = 0;
+    vault.total_deposited = vault.total_deposited
+        .checked_sub(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    msg!("Queued withdrawal executed: {} lamports. Total deposited: {}", amount, vault.total_deposited);
+    emit!(Withdrawn {
+        owner: vault.owner,
+        vault: vault.key(),
+        authority: ctx.accounts.user.key(),
+        amount,
+        remaining_allowance: None,
+        total_deposited: vault.total_deposited,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    // Owner or delegate - receives the withdrawn lamports
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::RewardPool;
+use crate::errors::VaultError;
+use crate::events::RewardPoolFunded;
+
+// INSTRUCTION: Fund Reward Pool
+// Admin transfers SOL into the reward pool so stakers can be paid
+
+pub fn fund_reward_pool(ctx: Context<FundRewardPool>, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    // CONCEPT: CPI to System Program
+    // Sending TO a program-owned PDA is fine - only debiting it needs the owner
+    let accounts = Transfer {
+        from: ctx.accounts.admin.to_account_info(),
+        to: ctx.accounts.reward_pool.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        accounts,
+    );
+
+    transfer(cpi_context, amount)?;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    reward_pool.total_funded = reward_pool.total_funded
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    msg!("Funded reward pool with {} lamports. Total funded: {}", amount, reward_pool.total_funded);
+    emit!(RewardPoolFunded {
+        admin: reward_pool.admin,
+        amount,
+        total_funded: reward_pool.total_funded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundRewardPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+        has_one = admin @ VaultError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::StakePool;
+use crate::errors::VaultError;
+use crate::rewards::update_stake_pool;
+use crate::events::StakePoolFunded;
+
+// INSTRUCTION: Fund Stake Pool
+// Admin adds SOL to the emission budget shared by all stakers
+
+pub fn fund_stake_pool(ctx: Context<FundStakePool>, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    // Account for emissions up to now first, so the new budget only
+    // starts emitting from this moment on
+    let clock = Clock::get()?;
+    update_stake_pool(&mut ctx.accounts.stake_pool, clock.unix_timestamp)?;
+
+    // Sending TO a program-owned PDA is fine - only debiting it needs the owner
+    let accounts = Transfer {
+        from: ctx.accounts.admin.to_account_info(),
+        to: ctx.accounts.stake_pool.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        accounts,
+    );
+
+    transfer(cpi_context, amount)?;
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    stake_pool.undistributed = stake_pool.undistributed
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    stake_pool.total_funded = stake_pool.total_funded
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    msg!(
+        "Funded stake pool with {} lamports. Undistributed budget: {}",
+        amount,
+        stake_pool.undistributed
+    );
+    emit!(StakePoolFunded {
+        admin: stake_pool.admin,
+        amount,
+        undistributed: stake_pool.undistributed,
+        total_funded: stake_pool.total_funded,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundStakePool<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump,
+        has_one = admin @ VaultError::Unauthorized
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, Delegation, StakingConfig};
+use crate::errors::VaultError;
+use crate::events::DelegationGranted;
+
+// INSTRUCTION: Grant Delegation
+// Lets the vault owner give a key (e.g. a treasury bot) a limited withdraw
+// right: at most `limit_per_period` lamports per `period_duration` seconds,
+// until `expires_at`. The key can't stake, unstake or change any settings.
+
+pub fn grant_delegation(
+    ctx: Context<GrantDelegation>,
+    delegate: Pubkey,
+    limit_per_period: u64,
+    period_duration: i64,
+    expires_at: i64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        limit_per_period > 0 && period_duration > 0 && expires_at > current_time,
+        VaultError::InvalidDelegation
+    );
+
+    // Counted so close_vault can insist every grant is revoked first
+    let vault = &mut ctx.accounts.vault;
+    vault.open_delegations = vault.open_delegations
+        .checked_add(1)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    let delegation = &mut ctx.accounts.delegation;
+    delegation.vault = vault.key();
+    delegation.delegate = delegate;
+    delegation.granted_by = ctx.accounts.owner.key();
+    delegation.owner_epoch = vault.owner_epoch;
+    delegation.limit_per_period = limit_per_period;
+    delegation.period_duration = period_duration;
+    delegation.expires_at = expires_at;
+    delegation.bump = ctx.bumps.delegation;
+
+    // CONCEPT: Time-locked Grants
+    // A grant is a withdraw right, so it waits out the same withdrawal delay
+    // as queued withdrawals - otherwise a stolen owner key could grant itself
+    // a huge allowance and skip the queue
+    delegation.active_from = current_time
+        .checked_add(ctx.accounts.config.withdrawal_delay)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    // Starts with a full allowance once the grant is active
+    delegation.last_refill = delegation.active_from;
+    delegation.allowance = limit_per_period;
+
+    msg!(
+        "Delegation granted to {:?}: {} lamports every {}s until {}",
+        delegate,
+        limit_per_period,
+        period_duration,
+        expires_at
+    );
+    emit!(DelegationGranted {
+        owner: ctx.accounts.owner.key(),
+        vault: delegation.vault,
+        delegate,
+        limit_per_period,
+        period_duration,
+        expires_at,
+        active_from: delegation.active_from,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct GrantDelegation<'info> {
+    // Owner-only, like set_delegate
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    // CONCEPT: Instruction Arguments in Seeds
+    // #[instruction(...)] exposes the handler's arguments to the constraints,
+    // so the delegate pubkey can be part of the PDA seeds.
+    // `init` fails if a grant already exists - revoke it first to change it.
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Delegation::INIT_SPACE,
+        seeds = [b"delegation", vault.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    // Read for the withdrawal delay
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
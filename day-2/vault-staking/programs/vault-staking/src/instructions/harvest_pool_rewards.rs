@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakePool};
+use crate::errors::VaultError;
+use crate::rewards::{update_stake_pool, enroll_in_stake_pool, settle_pool_reward};
+use crate::events::{RewardPaid, RewardSource};
+
+// INSTRUCTION: Harvest Pool Rewards
+// Pays the vault its share of the stake pool emissions without touching
+// any stake position. Stake pool rewards are also settled automatically
+// whenever the vault's staked amount changes.
+
+pub fn harvest_pool_rewards(ctx: Context<HarvestPoolRewards>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let stake_pool = &mut ctx.accounts.stake_pool;
+
+    let clock = Clock::get()?;
+    update_stake_pool(stake_pool, clock.unix_timestamp)?;
+
+    // A vault that staked before the pool existed has earned nothing yet.
+    // Enrolling is the whole point of this call, so it succeeds instead of
+    // failing with NoRewardsToClaim and rolling the enrollment back.
+    if !vault.in_stake_pool {
+        enroll_in_stake_pool(stake_pool, vault)?;
+        msg!(
+            "Enrolled {} staked lamports in the stake pool; rewards accrue from now on",
+            vault.staked_amount
+        );
+        return Ok(());
+    }
+
+    let harvested = settle_pool_reward(stake_pool, vault)?;
+    require!(harvested > 0, VaultError::NoRewardsToClaim);
+
+    msg!(
+        "Harvested {} lamports from the stake pool. Total balance now: {} lamports",
+        harvested,
+        vault.total_deposited
+    );
+    emit!(RewardPaid {
+        owner: vault.owner,
+        vault: vault.key(),
+        position_index: None,
+        source: RewardSource::StakePool,
+        amount: harvested,
+        total_deposited: vault.total_deposited,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HarvestPoolRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump,
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    pub user: Signer<'info>,
+}
+
+// CONCEPT: Why Payouts Stay Exact
+// A vault's share is staked_amount * reward_per_share - reward_debt.
+// reward_debt is reset every time the vault's stake changes, so each
+// lamport of stake only earns the index growth that happened while it
+// was staked - no matter how many other users join or leave in between.
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::HeartbeatRecorded;
+
+// INSTRUCTION: Heartbeat
+// Proves the owner is still around without moving any funds.
+// Resets the dead-man's switch timer.
+
+pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    vault.record_owner_activity(&ctx.accounts.owner.key(), current_time);
+
+    msg!("Heartbeat recorded at {}", current_time);
+    emit!(HeartbeatRecorded {
+        owner: vault.owner,
+        vault: vault.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    // Owner-only: a delegate's activity doesn't prove the owner is alive
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+}
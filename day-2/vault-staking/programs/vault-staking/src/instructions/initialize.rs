@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::Vault;
+use crate::events::VaultInitialized;
 
 // CONCEPT: Modular Instructions
 // Each instruction gets its own file for better organization
@@ -14,16 +15,52 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     // Set the owner
     vault.owner = ctx.accounts.user.key();
 
+    // CONCEPT: Stable PDA Seeds
+    // The vault address is derived from its creator, not the current owner,
+    // so propose_owner / accept_owner can change `owner` without moving funds
+    vault.creator = ctx.accounts.user.key();
+    vault.pending_owner = None;
+    vault.owner_transfer_unlock_time = 0;
+    vault.owner_epoch = 0;
+
     // Store the PDA bump seed
     vault.bump = ctx.bumps.vault;
 
     // Initialize staking fields to default values
     vault.total_deposited = 0;
     vault.staked_amount = 0;
-    vault.stake_timestamp = 0;
-    vault.is_staked = false;
+    vault.next_position_index = 0;
+
+    // No delegate until the owner explicitly sets one
+    vault.delegate = None;
+    vault.open_delegations = 0;
+
+    // Compounding is opt-in
+    vault.auto_compound = false;
+
+    // Nothing is unbonding yet
+    vault.pending_unstake = 0;
+    vault.unbonding_end = 0;
+
+    // Nothing staked, so no stake pool rewards to account for
+    vault.reward_debt = 0;
+    vault.in_stake_pool = false;
+
+    // Nothing queued for withdrawal
+    vault.queued_withdrawal = 0;
+    vault.withdrawal_unlock_time = 0;
+
+    // No beneficiary until the owner names one; creating the vault counts as activity
+    vault.beneficiary = None;
+    vault.inactivity_window = 0;
+    vault.last_owner_activity = Clock::get()?.unix_timestamp;
 
     msg!("Vault initialized for owner: {:?}", vault.owner);
+    emit!(VaultInitialized {
+        owner: vault.owner,
+        vault: vault.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
 
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::program::VaultStaking;
+use crate::state::StakingConfig;
+use crate::constants::*;
+use crate::errors::VaultError;
+use crate::events::ConfigInitialized;
+
+// INSTRUCTION: Initialize Config
+// Creates the program-wide staking config with the default parameters
+// The upgrade authority becomes the config admin (the only key allowed to update it)
+
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.bump = ctx.bumps.config;
+
+    // Start from the compile-time defaults
+    config.min_stake_duration = MIN_STAKE_DURATION;
+    config.reward_rate_basis_points = REWARD_RATE_BASIS_POINTS;
+    config.compound_tip_basis_points = COMPOUND_TIP_BASIS_POINTS;
+    config.unbonding_period = UNBONDING_PERIOD;
+    config.early_unstake_penalty_basis_points = EARLY_UNSTAKE_PENALTY_BASIS_POINTS;
+    config.withdrawal_delay = WITHDRAWAL_DELAY;
+    config.lock_multiplier_one_day_basis_points = LOCK_MULTIPLIER_ONE_DAY;
+    config.lock_multiplier_seven_days_basis_points = LOCK_MULTIPLIER_SEVEN_DAYS;
+    config.lock_multiplier_thirty_days_basis_points = LOCK_MULTIPLIER_THIRTY_DAYS;
+    config.early_exit_penalty_basis_points = EARLY_EXIT_PENALTY_BASIS_POINTS;
+
+    // initialize_stake_pool turns this on
+    config.stake_pool_active = false;
+
+    msg!(
+        "Staking config initialized. Min duration: {}s, rate: {} bps APR, compound tip: {} bps, unbonding: {}s, early unstake penalty: {} bps, withdrawal delay: {}s, lock multipliers: {}/{}/{} bps, early exit penalty: {} bps",
+        config.min_stake_duration,
+        config.reward_rate_basis_points,
+        config.compound_tip_basis_points,
+        config.unbonding_period,
+        config.early_unstake_penalty_basis_points,
+        config.withdrawal_delay,
+        config.lock_multiplier_one_day_basis_points,
+        config.lock_multiplier_seven_days_basis_points,
+        config.lock_multiplier_thirty_days_basis_points,
+        config.early_exit_penalty_basis_points
+    );
+    emit!(ConfigInitialized {
+        admin: config.admin,
+        min_stake_duration: config.min_stake_duration,
+        reward_rate_basis_points: config.reward_rate_basis_points,
+        compound_tip_basis_points: config.compound_tip_basis_points,
+        unbonding_period: config.unbonding_period,
+        early_unstake_penalty_basis_points: config.early_unstake_penalty_basis_points,
+        withdrawal_delay: config.withdrawal_delay,
+        lock_multiplier_one_day_basis_points: config.lock_multiplier_one_day_basis_points,
+        lock_multiplier_seven_days_basis_points: config.lock_multiplier_seven_days_basis_points,
+        lock_multiplier_thirty_days_basis_points: config.lock_multiplier_thirty_days_basis_points,
+        early_exit_penalty_basis_points: config.early_exit_penalty_basis_points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    // CONCEPT: Singleton PDA
+    // Seeds contain no user key, so there is exactly one config per program
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + StakingConfig::INIT_SPACE,
+        seeds = [b"staking_config"],
+        bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // CONCEPT: Upgrade Authority Gate
+    // An `init` singleton belongs to whoever calls first, so anyone watching
+    // the deploy could front-run it and become admin. Only the key that can
+    // upgrade the program (recorded in its ProgramData account) may create it.
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VaultStaking>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ VaultError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
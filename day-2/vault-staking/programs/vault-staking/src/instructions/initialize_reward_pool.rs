@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::program::VaultStaking;
+use crate::state::RewardPool;
+use crate::errors::VaultError;
+use crate::events::RewardPoolInitialized;
+
+// INSTRUCTION: Initialize Reward Pool
+// Creates the program-wide reward pool PDA
+// The upgrade authority becomes the pool admin (the only key allowed to fund it)
+
+pub fn initialize_reward_pool(ctx: Context<InitializeRewardPool>) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+
+    reward_pool.admin = ctx.accounts.admin.key();
+    reward_pool.bump = ctx.bumps.reward_pool;
+    reward_pool.total_funded = 0;
+    reward_pool.total_paid = 0;
+    reward_pool.total_penalties = 0;
+
+    msg!("Reward pool initialized. Admin: {:?}", reward_pool.admin);
+    emit!(RewardPoolInitialized {
+        admin: reward_pool.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRewardPool<'info> {
+    // CONCEPT: Singleton PDA
+    // Seeds contain no user key, so there is exactly one pool per program.
+    // `init` fails if it already exists - nobody can take over as admin later.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RewardPool::INIT_SPACE,
+        seeds = [b"reward_pool"],
+        bump
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // Only the upgrade authority can create it (see initialize_config)
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VaultStaking>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ VaultError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
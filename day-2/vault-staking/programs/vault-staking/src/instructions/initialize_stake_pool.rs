@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::program::VaultStaking;
+use crate::state::{StakePool, StakingConfig};
+use crate::errors::VaultError;
+use crate::events::StakePoolInitialized;
+
+// INSTRUCTION: Initialize Stake Pool
+// Creates the program-wide stake pool PDA that shares a fixed emission
+// budget between all stakers. The upgrade authority becomes the pool admin.
+
+pub fn initialize_stake_pool(ctx: Context<InitializeStakePool>, emission_rate: u64) -> Result<()> {
+    let stake_pool = &mut ctx.accounts.stake_pool;
+
+    stake_pool.admin = ctx.accounts.admin.key();
+    stake_pool.bump = ctx.bumps.stake_pool;
+    stake_pool.reward_per_share = 0;
+    stake_pool.last_update_timestamp = Clock::get()?.unix_timestamp;
+    stake_pool.emission_rate = emission_rate;
+    stake_pool.total_staked = 0;
+    stake_pool.undistributed = 0;
+    stake_pool.total_funded = 0;
+    stake_pool.total_paid = 0;
+
+    // From now on stake, request_unstake and compound must settle against the pool
+    ctx.accounts.config.stake_pool_active = true;
+
+    msg!(
+        "Stake pool initialized. Admin: {:?}, emission rate: {} lamports/s",
+        stake_pool.admin,
+        stake_pool.emission_rate
+    );
+    emit!(StakePoolInitialized {
+        admin: stake_pool.admin,
+        emission_rate: stake_pool.emission_rate,
+        timestamp: stake_pool.last_update_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeStakePool<'info> {
+    // CONCEPT: Singleton PDA
+    // One stake pool per program, like the reward pool and config
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + StakePool::INIT_SPACE,
+        seeds = [b"stake_pool"],
+        bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // Only the upgrade authority can create it (see initialize_config)
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VaultStaking>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ VaultError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -6,18 +6,82 @@
 // Declare modules (tells Rust these files exist)
 pub mod initialize;
 pub mod deposit;
+pub mod deposit_for;
 pub mod withdraw;
+pub mod transfer_between_vaults;
+pub mod queue_withdraw;
+pub mod execute_withdraw;
+pub mod cancel_withdraw;
+pub mod close_vault;
 pub mod stake;
-pub mod unstake;
+pub mod request_unstake;
+pub mod complete_unstake;
+pub mod claim_rewards;
+pub mod set_auto_compound;
+pub mod compound;
+pub mod set_delegate;
+pub mod grant_delegation;
+pub mod revoke_delegation;
+pub mod propose_owner;
+pub mod accept_owner;
+pub mod set_beneficiary;
+pub mod heartbeat;
+pub mod claim_inheritance;
+pub mod create_multisig;
+pub mod deposit_multisig;
+pub mod propose_withdrawal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod initialize_reward_pool;
+pub mod fund_reward_pool;
+pub mod initialize_stake_pool;
+pub mod fund_stake_pool;
+pub mod update_stake_pool_rate;
+pub mod harvest_pool_rewards;
+pub mod initialize_config;
+pub mod update_config;
+pub mod update_lock_tiers;
 
 // Re-export everything from each module
 // This allows: use crate::instructions::*;
 // Instead of: use crate::instructions::initialize::Initialize;
 pub use initialize::*;
 pub use deposit::*;
+pub use deposit_for::*;
 pub use withdraw::*;
+pub use transfer_between_vaults::*;
+pub use queue_withdraw::*;
+pub use execute_withdraw::*;
+pub use cancel_withdraw::*;
+pub use close_vault::*;
 pub use stake::*;
-pub use unstake::*;
+pub use request_unstake::*;
+pub use complete_unstake::*;
+pub use claim_rewards::*;
+pub use set_auto_compound::*;
+pub use compound::*;
+pub use set_delegate::*;
+pub use grant_delegation::*;
+pub use revoke_delegation::*;
+pub use propose_owner::*;
+pub use accept_owner::*;
+pub use set_beneficiary::*;
+pub use heartbeat::*;
+pub use claim_inheritance::*;
+pub use create_multisig::*;
+pub use deposit_multisig::*;
+pub use propose_withdrawal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use initialize_reward_pool::*;
+pub use fund_reward_pool::*;
+pub use initialize_stake_pool::*;
+pub use fund_stake_pool::*;
+pub use update_stake_pool_rate::*;
+pub use harvest_pool_rewards::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use update_lock_tiers::*;
 
 // CONCEPT: Why This Pattern?
 // Benefits of using mod.rs:
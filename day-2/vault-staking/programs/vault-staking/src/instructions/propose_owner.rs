@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakingConfig};
+use crate::errors::VaultError;
+use crate::events::OwnershipProposed;
+
+// INSTRUCTION: Propose Owner
+// Step 1 of 2: the current owner nominates a new owner (e.g. when rotating
+// to a new wallet). Nothing changes hands until that key calls accept_owner,
+// and not before config.withdrawal_delay has passed.
+// Proposing again replaces the nominee and restarts the delay; None cancels.
+
+pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Option<Pubkey>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // CONCEPT: Time-locked Ownership Transfer
+    // Handing the vault to a new key is the biggest withdrawal of all, so it
+    // waits out the same delay as queued withdrawals and new Delegation grants.
+    // Otherwise a stolen owner key could move the vault to a second key at
+    // once and lock the real owner out before they can cancel anything.
+    vault.pending_owner = new_owner;
+    vault.owner_transfer_unlock_time = match new_owner {
+        Some(_) => current_time
+            .checked_add(ctx.accounts.config.withdrawal_delay)
+            .ok_or(VaultError::ArithmeticOverflow)?,
+        None => 0,
+    };
+
+    match new_owner {
+        Some(key) => msg!(
+            "Proposed new owner {:?}, can accept from {}",
+            key,
+            vault.owner_transfer_unlock_time
+        ),
+        None => msg!("Ownership proposal cancelled"),
+    }
+    emit!(OwnershipProposed {
+        owner: vault.owner,
+        vault: vault.key(),
+        pending_owner: new_owner,
+        unlock_time: vault.owner_transfer_unlock_time,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    // Owner-only, like set_delegate: a delegate can't give the vault away
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::{MultisigVault, Proposal};
+use crate::errors::VaultError;
+use crate::events::{WithdrawalProposed, ProposalApproved};
+
+// INSTRUCTION: Propose Withdrawal
+// A multisig signer proposes sending `amount` lamports to `recipient`.
+// Proposing counts as the proposer's own approval.
+
+pub fn propose_withdrawal(
+    ctx: Context<ProposeWithdrawal>,
+    amount: u64,
+    recipient: Pubkey,
+) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = ctx.accounts.proposer.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    proposal.multisig = multisig.key();
+    proposal.index = multisig.next_proposal_index;
+    proposal.proposer = proposer;
+    proposal.recipient = recipient;
+    proposal.amount = amount;
+    proposal.approvals = vec![proposer];
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    multisig.next_proposal_index = multisig.next_proposal_index
+        .checked_add(1)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    msg!(
+        "Proposal {}: send {} lamports to {:?} (1 of {} approvals)",
+        proposal.index,
+        amount,
+        recipient,
+        multisig.threshold
+    );
+    emit!(WithdrawalProposed {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        proposer,
+        recipient,
+        amount,
+        timestamp: current_time,
+    });
+    emit!(ProposalApproved {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        signer: proposer,
+        approvals: 1,
+        threshold: multisig.threshold,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.creator.as_ref()],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&proposer.key()) @ VaultError::NotMultisigSigner
+    )]
+    pub multisig: Account<'info, MultisigVault>,
+
+    // Same counter pattern as stake positions: the index only ever goes up
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", multisig.key().as_ref(), &multisig.next_proposal_index.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakingConfig};
+use crate::errors::VaultError;
+use crate::events::WithdrawalQueued;
+
+// INSTRUCTION: Queue Withdraw
+// Phase 1 of 2 of a time-locked withdrawal: reserves `amount` of the
+// available balance and starts the config.withdrawal_delay timer.
+// execute_withdraw pays it out after the delay; cancel_withdraw releases it.
+
+pub fn queue_withdraw(ctx: Context<QueueWithdraw>, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+    vault.record_owner_activity(&ctx.accounts.user.key(), current_time);
+
+    // Queued funds are excluded from get_available_balance, so they can't
+    // also be staked or queued twice
+    require!(
+        vault.get_available_balance() >= amount,
+        VaultError::InsufficientFunds
+    );
+
+    // CONCEPT: One Queue per Vault
+    // Like unbonding, the queue is a single bucket: queuing more adds to it
+    // and restarts the timer for the whole amount
+    vault.queued_withdrawal = vault.queued_withdrawal
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.withdrawal_unlock_time = current_time
+        .checked_add(ctx.accounts.config.withdrawal_delay)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    msg!(
+        "Queued {} lamports. {} lamports unlock at {}",
+        amount,
+        vault.queued_withdrawal,
+        vault.withdrawal_unlock_time
+    );
+    emit!(WithdrawalQueued {
+        owner: vault.owner,
+        vault: vault.key(),
+        authority: ctx.accounts.user.key(),
+        amount,
+        queued_withdrawal: vault.queued_withdrawal,
+        unlock_time: vault.withdrawal_unlock_time,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueueWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    pub user: Signer<'info>,
+}
+
+// CONCEPT: Why Time-lock Withdrawals?
+// With instant withdraw, whoever holds the key can empty the vault in one
+// transaction. With a delay, a thief has to queue first, and the queue is
+// visible on-chain (WithdrawalQueued). The real owner has until the unlock
+// time to cancel_withdraw. Ownership transfers wait out the same delay, so a
+// thief's propose_owner can be cancelled (propose_owner(None)) before it
+// takes effect.
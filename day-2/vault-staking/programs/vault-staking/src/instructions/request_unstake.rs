@@ -0,0 +1,339 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakePosition, RewardPool, StakePool, StakingConfig};
+use crate::errors::VaultError;
+use crate::constants::*;
+use crate::rewards::{
+    calculate_reward, pay_reward, reward_pool_available,
+    update_stake_pool, enroll_in_stake_pool, settle_pool_reward, sync_reward_debt,
+};
+use crate::events::{Unstaked, RewardPaid, RewardSource};
+
+// INSTRUCTION: Request Unstake
+// Phase 1 of 2: removes `amount` from one stake position and distributes the
+// rewards that part accrued over its stake duration (only if the minimum
+// duration was met). The principal then waits out the unbonding cooldown in
+// vault.pending_unstake until complete_unstake releases it.
+// The rest of the position stays staked and keeps its original timestamp
+// Locked positions can only be unstaked early by accepting a penalty
+// Unstaking before the cliff may also cost a (configurable) principal penalty
+
+pub fn request_unstake(
+    ctx: Context<RequestUnstake>,
+    amount: u64,
+    accept_early_exit_penalty: bool,
+) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let vault = &mut ctx.accounts.vault;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let position = &mut ctx.accounts.stake_position;
+    let config = &ctx.accounts.config;
+
+    // Check that the position actually holds funds
+    require!(position.amount > 0, VaultError::NotStaked);
+
+    // Can't release more than this position holds
+    require!(
+        amount <= position.amount,
+        VaultError::InsufficientStakedBalance
+    );
+
+    // CONCEPT: Time-based Rewards
+    // Get current time from Clock sysvar
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    // Calculate how long this position has been staked
+    let stake_duration = position.get_stake_duration(current_time);
+
+    msg!(
+        "Unstaking {} of {} lamports from position {}. Staked for {} seconds.",
+        amount,
+        position.amount,
+        position.index,
+        stake_duration
+    );
+
+    // CONCEPT: Lock Period with Early Exit
+    // Before lock_end the principal is locked. The user may still leave early,
+    // but only by explicitly accepting the penalty: the released part earns
+    // no rewards and loses config.early_exit_penalty_basis_points of its principal.
+    let is_early_exit = position.is_locked(current_time);
+    if is_early_exit {
+        require!(accept_early_exit_penalty, VaultError::LockPeriodActive);
+    }
+
+    // CONCEPT: Vesting Cliff + Linear Accrual
+    // Nothing is earned before the minimum duration (the "cliff").
+    // Once past it, rewards cover everything accrued since the last checkpoint
+    // (stake time, or the last claim_rewards), growing linearly.
+    // Only the released part is settled (pro rata); the rest keeps accruing.
+    let accrual_duration = position.get_accrual_duration(current_time);
+    let met_min_duration = position.has_met_min_duration(current_time, config.min_stake_duration);
+    let reward_amount = if is_early_exit {
+        msg!("⚠️ Early exit: position locked until {}. Rewards forfeited.", position.lock_end);
+        0
+    } else if met_min_duration {
+        let reward = calculate_reward(
+            amount,
+            config.reward_rate_basis_points,
+            position.lock_tier.multiplier_basis_points(config),
+            accrual_duration,
+        )?;
+
+        msg!(
+            "✅ Minimum duration met! Earned {} lamports reward ({} bps APR x {} bps over {}s)",
+            reward,
+            config.reward_rate_basis_points,
+            position.lock_tier.multiplier_basis_points(config),
+            accrual_duration
+        );
+        reward
+    } else {
+        msg!("❌ Minimum duration NOT met. No rewards earned. (Staked for {}s, needed {}s)",
+            stake_duration,
+            config.min_stake_duration
+        );
+        0
+    };
+
+    // CONCEPT: Paying Rewards from the Pool
+    // Rewards are real lamports moved from the reward pool PDA into the vault.
+    // The pool keeps its rent-exempt minimum; only the excess can be paid out.
+    // If the pool is short, we pay what it has instead of blocking the unstake,
+    // so the user's principal is never stuck behind an empty pool.
+    // The unpaid part is forfeited; Unstaked reports it as reward_shortfall,
+    // so indexers can tell what was earned from what was actually paid.
+    let reward_paid = reward_amount.min(reward_pool_available(reward_pool)?);
+    let reward_shortfall = reward_amount - reward_paid;
+
+    if reward_shortfall > 0 {
+        msg!(
+            "⚠️ Reward pool short: paying {} of {} lamports earned ({} forfeited)",
+            reward_paid,
+            reward_amount,
+            reward_shortfall
+        );
+    }
+
+    // CONCEPT: State Updates
+    // The reward actually received is available right away;
+    // the principal goes through the unbonding cooldown below
+    pay_reward(reward_pool, vault, reward_paid)?;
+    if reward_paid > 0 {
+        emit!(RewardPaid {
+            owner: vault.owner,
+            vault: vault.key(),
+            position_index: Some(position.index),
+            source: RewardSource::Unstake,
+            amount: reward_paid,
+            total_deposited: vault.total_deposited,
+            timestamp: current_time,
+        });
+    }
+
+    // Stake pool rewards earned at the old staked amount are paid before it shrinks
+    let mut pool_reward = 0;
+    if let Some(stake_pool) = ctx.accounts.stake_pool.as_mut() {
+        update_stake_pool(stake_pool, current_time)?;
+        enroll_in_stake_pool(stake_pool, vault)?;
+        pool_reward = settle_pool_reward(stake_pool, vault)?;
+    }
+    if pool_reward > 0 {
+        msg!("Settled {} lamports of stake pool rewards", pool_reward);
+        emit!(RewardPaid {
+            owner: vault.owner,
+            vault: vault.key(),
+            position_index: None,
+            source: RewardSource::StakePool,
+            amount: pool_reward,
+            total_deposited: vault.total_deposited,
+            timestamp: current_time,
+        });
+    }
+
+    // Release the unstaked principal; other positions stay staked
+    vault.staked_amount = vault.staked_amount
+        .checked_sub(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    if let Some(stake_pool) = ctx.accounts.stake_pool.as_mut() {
+        stake_pool.total_staked = stake_pool.total_staked
+            .checked_sub(amount)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        sync_reward_debt(stake_pool, vault)?;
+    }
+
+    // CONCEPT: Early Penalties
+    // Two ways to leave early, one penalty at a time:
+    // - Breaking a lock costs config.early_exit_penalty_basis_points (10% by default)
+    // - Leaving before the cliff costs config.early_unstake_penalty_basis_points
+    //   (0 unless the admin turns it on)
+    // When both apply, only the lock penalty is charged - they never stack.
+    let penalty_basis_points = if is_early_exit {
+        config.early_exit_penalty_basis_points
+    } else if !met_min_duration {
+        config.early_unstake_penalty_basis_points
+    } else {
+        0
+    };
+
+    // CONCEPT: Penalty Goes to the Reward Pool
+    // The forfeited principal funds future rewards for the stakers who stayed
+    let penalty = (amount as u128)
+        .checked_mul(penalty_basis_points as u128)
+        .ok_or(VaultError::ArithmeticOverflow)?
+        .checked_div(BASIS_POINTS_DIVISOR as u128)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    let penalty = u64::try_from(penalty).map_err(|_| VaultError::ArithmeticOverflow)?;
+
+    if penalty > 0 {
+        vault.sub_lamports(penalty)?;
+        reward_pool.add_lamports(penalty)?;
+
+        vault.total_deposited = vault.total_deposited
+            .checked_sub(penalty)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        reward_pool.total_penalties = reward_pool.total_penalties
+            .checked_add(penalty)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+    }
+
+    if is_early_exit {
+        msg!("Early-exit penalty: {} lamports moved to the reward pool", penalty);
+    } else if penalty > 0 {
+        msg!(
+            "Early unstake penalty ({} bps before the {}s cliff): {} lamports moved to the reward pool",
+            penalty_basis_points,
+            config.min_stake_duration,
+            penalty
+        );
+    }
+
+    // CONCEPT: Partial Unstake
+    // The remainder keeps its stake_timestamp, so its timer is not reset
+    position.amount = position.amount
+        .checked_sub(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    // CONCEPT: Unbonding
+    // The released principal (after any penalty) is NOT available yet.
+    // It joins the vault's pending bucket, and the cooldown restarts for the
+    // whole bucket - one timer per vault keeps the state small.
+    let unbonding_amount = amount
+        .checked_sub(penalty)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.pending_unstake = vault.pending_unstake
+        .checked_add(unbonding_amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.unbonding_end = current_time
+        .checked_add(config.unbonding_period)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    msg!(
+        "Unstake requested. {} lamports pending until {}. Total balance now: {} lamports",
+        vault.pending_unstake,
+        vault.unbonding_end,
+        vault.total_deposited
+    );
+
+    emit!(Unstaked {
+        owner: vault.owner,
+        vault: vault.key(),
+        position_index: position.index,
+        amount,
+        penalty,
+        reward_earned: reward_amount,
+        reward_shortfall,
+        position_closed: position.amount == 0,
+        staked_amount: vault.staked_amount,
+        pending_unstake: vault.pending_unstake,
+        unbonding_end: vault.unbonding_end,
+        timestamp: current_time,
+    });
+
+    // CONCEPT: Conditional Account Closure
+    // A `close` constraint would always close the account, so we close it
+    // by hand only once the position is empty. Its rent goes back to whoever
+    // paid it in `stake`, not to the signer - a delegate unstaking the owner's
+    // position must not pocket the owner's rent.
+    if position.amount == 0 {
+        msg!("Position {} fully unstaked and closed", position.index);
+        ctx.accounts.stake_position.close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    // has_one = vault ties the position to this vault
+    #[account(
+        mut,
+        seeds = [b"stake_position", vault.key().as_ref(), &stake_position.index.to_le_bytes()],
+        bump = stake_position.bump,
+        has_one = vault,
+        has_one = payer
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    // Optional stake pool: staking works before it exists, but once it
+    // does every stake change has to settle against it
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+        constraint = stake_pool.is_some() || !config.stake_pool_active @ VaultError::StakePoolRequired
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    // Global emissions accumulator; settled before the stake changes
+    // (None until initialize_stake_pool has run)
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump,
+    )]
+    pub stake_pool: Option<Account<'info, StakePool>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Paid the position's rent in `stake`; gets it back when it is fully unstaked
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+}
+
+// CONCEPT: Where Do Rewards Come From?
+// Rewards are paid from the reward pool PDA, which an admin funds with SOL
+// (see fund_reward_pool.rs). Nothing is "printed": every lamport credited
+// to total_deposited actually arrives in the vault.
+//
+// In production systems, the pool is typically filled by:
+// 1. Token emissions scheduled by the protocol
+// 2. Protocol fees collected from users
+// 3. Yield from DeFi strategies (e.g., lending)
+
+// CONCEPT: Checked Math is Critical
+// Without checked math:
+// - Overflow could wrap around (bad!)
+// - User might get wrong reward amount
+// - Could be exploited by attackers
+//
+// With checked math:
+// - Returns None on overflow
+// - Transaction fails safely
+// - Prevents exploitation
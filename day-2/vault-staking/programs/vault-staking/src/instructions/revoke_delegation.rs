@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, Delegation};
+use crate::errors::VaultError;
+use crate::events::DelegationRevoked;
+
+// INSTRUCTION: Revoke Delegation
+// Deletes a Delegation PDA so its key can't withdraw anymore.
+// The current owner revokes, but the rent goes back to whoever paid it:
+// the owner who granted it, who may no longer own the vault.
+
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.open_delegations = vault.open_delegations
+        .checked_sub(1)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    let delegation = &ctx.accounts.delegation;
+
+    msg!("Delegation revoked for {:?}", delegation.delegate);
+    emit!(DelegationRevoked {
+        owner: ctx.accounts.owner.key(),
+        vault: delegation.vault,
+        delegate: delegation.delegate,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    // has_one = vault ties the grant to this vault
+    #[account(
+        mut,
+        seeds = [b"delegation", vault.key().as_ref(), delegation.delegate.as_ref()],
+        bump = delegation.bump,
+        has_one = vault,
+        has_one = granted_by,
+        close = granted_by
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Receives the rent; checked against delegation.granted_by
+    #[account(mut)]
+    pub granted_by: SystemAccount<'info>,
+}
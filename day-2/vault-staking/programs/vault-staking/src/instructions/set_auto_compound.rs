@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::AutoCompoundUpdated;
+
+// INSTRUCTION: Set Auto Compound
+// Lets the vault owner opt in (or out) of auto-compounding.
+// While enabled, ANYONE may call `compound` on this vault's positions.
+
+pub fn set_auto_compound(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.auto_compound = enabled;
+
+    msg!("Auto-compound {}", if enabled { "enabled" } else { "disabled" });
+    emit!(AutoCompoundUpdated {
+        owner: vault.owner,
+        vault: vault.key(),
+        enabled,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAutoCompound<'info> {
+    // Owner-only, like set_delegate: has_one = owner checks the signer
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+}
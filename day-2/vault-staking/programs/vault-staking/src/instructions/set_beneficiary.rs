@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::BeneficiaryUpdated;
+
+// INSTRUCTION: Set Beneficiary
+// Arms (or disarms) the dead-man's switch: if the owner performs no deposit,
+// withdraw, stake or heartbeat for `inactivity_window` seconds, the
+// beneficiary may claim the vault. None removes the beneficiary.
+
+pub fn set_beneficiary(
+    ctx: Context<SetBeneficiary>,
+    beneficiary: Option<Pubkey>,
+    inactivity_window: i64,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let inactivity_window = match beneficiary {
+        Some(_) => {
+            require!(inactivity_window > 0, VaultError::InvalidInactivityWindow);
+            inactivity_window
+        }
+        None => 0,
+    };
+
+    vault.beneficiary = beneficiary;
+    vault.inactivity_window = inactivity_window;
+
+    // Configuring the switch is itself a sign of life - otherwise arming it
+    // on a long-idle vault would make it claimable immediately
+    vault.record_owner_activity(&ctx.accounts.owner.key(), current_time);
+
+    match beneficiary {
+        Some(key) => msg!(
+            "Beneficiary set to {:?} after {} seconds of inactivity",
+            key,
+            inactivity_window
+        ),
+        None => msg!("Beneficiary removed"),
+    }
+    emit!(BeneficiaryUpdated {
+        owner: vault.owner,
+        vault: vault.key(),
+        beneficiary,
+        inactivity_window,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    // Owner-only: a delegate must not be able to name themselves heir
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+}
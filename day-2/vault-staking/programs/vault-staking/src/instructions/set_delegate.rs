@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::DelegateUpdated;
+
+// INSTRUCTION: Set Delegate
+// Lets the vault owner authorize (or revoke) a second key that may
+// withdraw, stake and unstake (request + complete) on their behalf
+
+pub fn set_delegate(ctx: Context<SetDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.delegate = delegate;
+
+    match delegate {
+        Some(key) => msg!("Delegate set to {:?}", key),
+        None => msg!("Delegate revoked"),
+    }
+    emit!(DelegateUpdated {
+        owner: vault.owner,
+        vault: vault.key(),
+        delegate,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDelegate<'info> {
+    // CONCEPT: Owner-only Instruction
+    // has_one = owner requires the signer to be the CURRENT owner
+    // (the seeds use the creator, which stays fixed across ownership transfers).
+    // The delegate can NOT call this - it can't appoint or remove delegates.
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub owner: Signer<'info>,
+}
@@ -1,20 +1,18 @@
 use anchor_lang::prelude::*;
-use crate::state::Vault;
+use crate::state::{Vault, StakePosition, StakePool, StakingConfig, LockTier};
 use crate::errors::VaultError;
+use crate::rewards::{update_stake_pool, enroll_in_stake_pool, settle_pool_reward, sync_reward_debt};
+use crate::events::{Staked, RewardPaid, RewardSource};
 
 // INSTRUCTION: Stake
-// Locks deposited funds for staking to earn rewards
+// Locks deposited funds in a NEW stake position to earn rewards
+// The lock tier sets how long the principal is locked and the reward multiplier
 
-pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+pub fn stake(ctx: Context<Stake>, amount: u64, lock_tier: LockTier) -> Result<()> {
     require!(amount > 0, VaultError::InvalidAmount);
 
     let vault = &mut ctx.accounts.vault;
 
-    // CONCEPT: Business Logic - Prevent double staking
-    // For simplicity, we only allow one active stake at a time
-    // In a more complex system, you could have multiple stakes
-    require!(!vault.is_staked, VaultError::AlreadyStaked);
-
     // Check user has enough unstaked balance
     let available_balance = vault.get_available_balance();
     require!(
@@ -29,16 +27,81 @@ pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
 
-    // Update vault state to reflect staking
-    vault.staked_amount = amount;
-    vault.stake_timestamp = current_time;
-    vault.is_staked = true;
+    // The owner staking proves they're alive (dead-man's switch)
+    vault.record_owner_activity(&ctx.accounts.user.key(), current_time);
+
+    // Record the new position with its own amount and start time
+    let position = &mut ctx.accounts.stake_position;
+    position.vault = vault.key();
+    position.index = vault.next_position_index;
+    position.amount = amount;
+    position.stake_timestamp = current_time;
+    position.last_claim_timestamp = current_time;
+    position.lock_tier = lock_tier;
+    position.lock_end = current_time
+        .checked_add(lock_tier.lock_duration())
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    position.payer = ctx.accounts.user.key();
+    position.bump = ctx.bumps.stake_position;
+
+    // CONCEPT: Settle Before the Stake Changes
+    // Stake pool rewards earned at the OLD staked amount are paid out first,
+    // so the new stake only earns emissions from now on
+    let mut pool_reward = 0;
+    if let Some(stake_pool) = ctx.accounts.stake_pool.as_mut() {
+        update_stake_pool(stake_pool, current_time)?;
+        enroll_in_stake_pool(stake_pool, vault)?;
+        pool_reward = settle_pool_reward(stake_pool, vault)?;
+    }
+    if pool_reward > 0 {
+        msg!("Settled {} lamports of stake pool rewards", pool_reward);
+        emit!(RewardPaid {
+            owner: vault.owner,
+            vault: vault.key(),
+            position_index: None,
+            source: RewardSource::StakePool,
+            amount: pool_reward,
+            total_deposited: vault.total_deposited,
+            timestamp: current_time,
+        });
+    }
+
+    // The vault tracks the sum of all open positions
+    vault.staked_amount = vault.staked_amount
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    if let Some(stake_pool) = ctx.accounts.stake_pool.as_mut() {
+        stake_pool.total_staked = stake_pool.total_staked
+            .checked_add(amount)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        sync_reward_debt(stake_pool, vault)?;
+    }
+    vault.next_position_index = vault.next_position_index
+        .checked_add(1)
+        .ok_or(VaultError::ArithmeticOverflow)?;
 
     msg!(
-        "Staked {} lamports at timestamp {}. Must wait 60 seconds for rewards.",
+        "Staked {} lamports in position {} at timestamp {}. Must wait {} seconds for rewards.",
         amount,
-        current_time
+        position.index,
+        current_time,
+        ctx.accounts.config.min_stake_duration
     );
+    msg!(
+        "Locked until {} ({} bps reward multiplier)",
+        position.lock_end,
+        lock_tier.multiplier_basis_points(&ctx.accounts.config)
+    );
+    emit!(Staked {
+        owner: vault.owner,
+        vault: vault.key(),
+        position_index: position.index,
+        amount,
+        lock_tier,
+        lock_end: position.lock_end,
+        staked_amount: vault.staked_amount,
+        timestamp: current_time,
+    });
 
     Ok(())
 }
@@ -47,34 +110,71 @@ pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
 pub struct Stake<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.owner.as_ref()],
+        seeds = [b"vault", vault.creator.as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_authorized(&user.key()) @ VaultError::Unauthorized
     )]
     pub vault: Account<'info, Vault>,
 
+    // CONCEPT: Counter-based PDA
+    // The vault's next_position_index makes every position address unique,
+    // so a new stake never collides with (or resets) an existing one
+    #[account(
+        init,
+        payer = user,
+        space = 8 + StakePosition::INIT_SPACE,
+        seeds = [b"stake_position", vault.key().as_ref(), &vault.next_position_index.to_le_bytes()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    // Optional stake pool: staking works before it exists, but once it
+    // does every stake change has to settle against it
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+        constraint = stake_pool.is_some() || !config.stake_pool_active @ VaultError::StakePoolRequired
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    // Global emissions accumulator; settled before the stake changes
+    // (None until initialize_stake_pool has run)
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump,
+    )]
+    pub stake_pool: Option<Account<'info, StakePool>>,
+
+    // Pays the rent for the new position account
     #[account(mut)]
     pub user: Signer<'info>,
 
-    /// CHECK: Verified through has_one constraint
-    pub owner: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 // CONCEPT: Why Track Timestamp?
 // We need to know WHEN staking started to:
-// 1. Check if minimum duration has been met (60 seconds)
+// 1. Check if minimum duration has been met (config.min_stake_duration)
 // 2. Calculate reward eligibility
 //
 // Unix timestamp is deterministic - all validators agree on it
 // This prevents "gaming" the system with different times
 
-// CONCEPT: Single Stake Design
-// This simple version only allows one stake at a time:
-// - Easier to understand for beginners
-// - Simpler state management
-// - Still demonstrates core concepts
+// CONCEPT: Lock Tiers
+// Users trade liquidity for yield:
+// - None:       withdraw any time, 1x rewards
+// - OneDay:     1.1x rewards
+// - SevenDays:  1.25x rewards
+// - ThirtyDays: 1.5x rewards
+// The min_stake_duration cliff still applies on top of any lock.
+
+// CONCEPT: Multiple Stake Positions
+// Each call to stake opens a separate StakePosition PDA:
+// - Adding to your stake never resets the timer on older positions
+// - Each position accrues rewards on its own amount and duration
+// - request_unstake targets one position at a time
 //
-// Advanced version could allow:
-// - Multiple concurrent stakes
-// - Different lock periods with different rates
-// - Compounding rewards
+// The position rent is paid by the signer and refunded to that same key
+// (position.payer) when the position is fully unstaked - even if someone
+// else, like the delegate, does the unstaking.
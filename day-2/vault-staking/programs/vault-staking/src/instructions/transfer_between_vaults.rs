@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, StakingConfig};
+use crate::errors::VaultError;
+use crate::events::InternalTransfer;
+
+// INSTRUCTION: Transfer Between Vaults
+// Moves available (unstaked) balance straight from one vault to another.
+// Replaces withdraw-to-wallet + deposit: one transaction, one event that
+// names both vaults.
+
+pub fn transfer_between_vaults(ctx: Context<TransferBetweenVaults>, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let from_vault = &mut ctx.accounts.from_vault;
+    let to_vault = &mut ctx.accounts.to_vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Money leaving the vault is a withdrawal as far as the time lock goes -
+    // otherwise a stolen key could skip the queue by moving funds to its own vault
+    require!(
+        ctx.accounts.config.withdrawal_delay == 0,
+        VaultError::WithdrawalDelayActive
+    );
+
+    require!(
+        from_vault.get_available_balance() >= amount,
+        VaultError::InsufficientFunds
+    );
+
+    // Same rent rule as withdraw: the source keeps its rent-exempt minimum
+    let from_info = from_vault.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(from_info.data_len());
+    let withdrawable_lamports = from_info.lamports().saturating_sub(rent_minimum);
+    require!(
+        withdrawable_lamports >= amount,
+        VaultError::InsufficientFunds
+    );
+
+    // CONCEPT: Program-to-Program Lamport Move
+    // Both vaults are owned by this program, so no System Program CPI is needed
+    from_vault.sub_lamports(amount)?;
+    to_vault.add_lamports(amount)?;
+
+    from_vault.total_deposited = from_vault.total_deposited
+        .checked_sub(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    to_vault.total_deposited = to_vault.total_deposited
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    from_vault.record_owner_activity(&ctx.accounts.user.key(), current_time);
+
+    msg!(
+        "Transferred {} lamports between vaults. Source total: {}, destination total: {}",
+        amount,
+        from_vault.total_deposited,
+        to_vault.total_deposited
+    );
+    emit!(InternalTransfer {
+        from_owner: from_vault.owner,
+        from_vault: from_vault.key(),
+        to_owner: to_vault.owner,
+        to_vault: to_vault.key(),
+        authority: ctx.accounts.user.key(),
+        amount,
+        from_total_deposited: from_vault.total_deposited,
+        to_total_deposited: to_vault.total_deposited,
+        timestamp: current_time,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferBetweenVaults<'info> {
+    // Same authority as withdraw: the owner or the full delegate
+    #[account(
+        mut,
+        seeds = [b"vault", from_vault.creator.as_ref()],
+        bump = from_vault.bump,
+        constraint = from_vault.is_authorized(&user.key()) @ VaultError::Unauthorized
+    )]
+    pub from_vault: Account<'info, Vault>,
+
+    // Any vault can receive, like deposit_for
+    #[account(
+        mut,
+        seeds = [b"vault", to_vault.creator.as_ref()],
+        bump = to_vault.bump,
+        constraint = to_vault.key() != from_vault.key() @ VaultError::SameVault
+    )]
+    pub to_vault: Account<'info, Vault>,
+
+    // Read for the withdrawal delay
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    pub user: Signer<'info>,
+}
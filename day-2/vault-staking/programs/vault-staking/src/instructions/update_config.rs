@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::StakingConfig;
+use crate::errors::VaultError;
+use crate::constants::*;
+use crate::events::ConfigUpdated;
+
+// INSTRUCTION: Update Config
+// Admin changes the staking parameters - takes effect on the next stake/request_unstake
+
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    min_stake_duration: i64,
+    reward_rate_basis_points: u64,
+    compound_tip_basis_points: u64,
+    unbonding_period: i64,
+    early_unstake_penalty_basis_points: u64,
+    withdrawal_delay: i64,
+) -> Result<()> {
+    require!(min_stake_duration >= 0, VaultError::InvalidConfig);
+    require!(unbonding_period >= 0, VaultError::InvalidConfig);
+    require!(withdrawal_delay >= 0, VaultError::InvalidConfig);
+
+    // A tip above 100% of the reward makes no sense
+    require!(
+        compound_tip_basis_points <= BASIS_POINTS_DIVISOR,
+        VaultError::InvalidConfig
+    );
+
+    // Neither does a penalty above 100% of the principal
+    require!(
+        early_unstake_penalty_basis_points <= BASIS_POINTS_DIVISOR,
+        VaultError::InvalidConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.min_stake_duration = min_stake_duration;
+    config.reward_rate_basis_points = reward_rate_basis_points;
+    config.compound_tip_basis_points = compound_tip_basis_points;
+    config.unbonding_period = unbonding_period;
+    config.early_unstake_penalty_basis_points = early_unstake_penalty_basis_points;
+    config.withdrawal_delay = withdrawal_delay;
+
+    msg!(
+        "Staking config updated. Min duration: {}s, rate: {} bps APR, compound tip: {} bps, unbonding: {}s, early unstake penalty: {} bps, withdrawal delay: {}s",
+        config.min_stake_duration,
+        config.reward_rate_basis_points,
+        config.compound_tip_basis_points,
+        config.unbonding_period,
+        config.early_unstake_penalty_basis_points,
+        config.withdrawal_delay
+    );
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        min_stake_duration: config.min_stake_duration,
+        reward_rate_basis_points: config.reward_rate_basis_points,
+        compound_tip_basis_points: config.compound_tip_basis_points,
+        unbonding_period: config.unbonding_period,
+        early_unstake_penalty_basis_points: config.early_unstake_penalty_basis_points,
+        withdrawal_delay: config.withdrawal_delay,
+        lock_multiplier_one_day_basis_points: config.lock_multiplier_one_day_basis_points,
+        lock_multiplier_seven_days_basis_points: config.lock_multiplier_seven_days_basis_points,
+        lock_multiplier_thirty_days_basis_points: config.lock_multiplier_thirty_days_basis_points,
+        early_exit_penalty_basis_points: config.early_exit_penalty_basis_points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = config.bump,
+        has_one = admin @ VaultError::Unauthorized
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+// CONCEPT: Parameter Changes Apply to Open Stakes
+// Rewards are computed at request_unstake time with the CURRENT config
+// (including the lock multipliers set by update_lock_tiers).
+// Raising the rate benefits existing stakers; lowering it reduces
+// what they earn. Real protocols often announce changes in advance.
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::StakingConfig;
+use crate::errors::VaultError;
+use crate::constants::*;
+use crate::events::ConfigUpdated;
+
+// INSTRUCTION: Update Lock Tiers
+// Admin changes the lock tier economics: the reward multiplier of each tier
+// and the penalty for breaking a lock early. The lock durations stay fixed
+// (see constants.rs), and LockTier::None always earns exactly 1x.
+
+pub fn update_lock_tiers(
+    ctx: Context<UpdateLockTiers>,
+    one_day_basis_points: u64,
+    seven_days_basis_points: u64,
+    thirty_days_basis_points: u64,
+    early_exit_penalty_basis_points: u64,
+) -> Result<()> {
+    // A lock never earns less than no lock, and a longer lock never earns
+    // less than a shorter one - otherwise nobody would pick it
+    require!(
+        LOCK_MULTIPLIER_NONE <= one_day_basis_points
+            && one_day_basis_points <= seven_days_basis_points
+            && seven_days_basis_points <= thirty_days_basis_points,
+        VaultError::InvalidConfig
+    );
+
+    // Same bound as update_config's penalty: never more than the principal
+    require!(
+        early_exit_penalty_basis_points <= BASIS_POINTS_DIVISOR,
+        VaultError::InvalidConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.lock_multiplier_one_day_basis_points = one_day_basis_points;
+    config.lock_multiplier_seven_days_basis_points = seven_days_basis_points;
+    config.lock_multiplier_thirty_days_basis_points = thirty_days_basis_points;
+    config.early_exit_penalty_basis_points = early_exit_penalty_basis_points;
+
+    msg!(
+        "Lock tiers updated. Multipliers: {}/{}/{} bps, early exit penalty: {} bps",
+        config.lock_multiplier_one_day_basis_points,
+        config.lock_multiplier_seven_days_basis_points,
+        config.lock_multiplier_thirty_days_basis_points,
+        config.early_exit_penalty_basis_points
+    );
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        min_stake_duration: config.min_stake_duration,
+        reward_rate_basis_points: config.reward_rate_basis_points,
+        compound_tip_basis_points: config.compound_tip_basis_points,
+        unbonding_period: config.unbonding_period,
+        early_unstake_penalty_basis_points: config.early_unstake_penalty_basis_points,
+        withdrawal_delay: config.withdrawal_delay,
+        lock_multiplier_one_day_basis_points: config.lock_multiplier_one_day_basis_points,
+        lock_multiplier_seven_days_basis_points: config.lock_multiplier_seven_days_basis_points,
+        lock_multiplier_thirty_days_basis_points: config.lock_multiplier_thirty_days_basis_points,
+        early_exit_penalty_basis_points: config.early_exit_penalty_basis_points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateLockTiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_config"],
+        bump = config.bump,
+        has_one = admin @ VaultError::Unauthorized
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+// CONCEPT: Changing Locks Under Open Positions
+// Positions keep their lock_end, but the multiplier and the penalty are read
+// at settlement time. A higher penalty therefore also applies to locks that
+// are already open - in production this would sit behind a timelock.
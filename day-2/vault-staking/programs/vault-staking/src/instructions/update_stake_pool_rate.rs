@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::StakePool;
+use crate::errors::VaultError;
+use crate::rewards::update_stake_pool;
+use crate::events::StakePoolRateUpdated;
+
+// INSTRUCTION: Update Stake Pool Rate
+// Admin changes how many lamports per second the stake pool emits
+
+pub fn update_stake_pool_rate(ctx: Context<UpdateStakePoolRate>, emission_rate: u64) -> Result<()> {
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    let clock = Clock::get()?;
+
+    // CONCEPT: Settle at the Old Rate First
+    // Everything emitted up to now was earned at the old rate, so it goes into
+    // reward_per_share before the rate changes. Otherwise the new rate would
+    // apply retroactively to the whole time since the last update.
+    update_stake_pool(stake_pool, clock.unix_timestamp)?;
+
+    let previous_rate = stake_pool.emission_rate;
+    stake_pool.emission_rate = emission_rate;
+
+    msg!(
+        "Stake pool emission rate changed from {} to {} lamports/s",
+        previous_rate,
+        emission_rate
+    );
+    emit!(StakePoolRateUpdated {
+        admin: stake_pool.admin,
+        previous_rate,
+        emission_rate,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateStakePoolRate<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump,
+        has_one = admin @ VaultError::Unauthorized
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    pub admin: Signer<'info>,
+}
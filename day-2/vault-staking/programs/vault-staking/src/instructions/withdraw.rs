@@ -1,18 +1,62 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::Vault;
+use crate::state::{Vault, Delegation, StakingConfig};
 use crate::errors::VaultError;
+use crate::events::Withdrawn;
 
 // INSTRUCTION: Withdraw
 // Transfers SOL from vault back to user (only unstaked funds)
+// While config.withdrawal_delay is on, the owner and delegate must go
+// through queue_withdraw / execute_withdraw instead
 
 pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     require!(amount > 0, VaultError::InvalidAmount);
 
     let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+    vault.record_owner_activity(&ctx.accounts.user.key(), current_time);
+
+    // CONCEPT: Spending Limits
+    // The owner and the full delegate withdraw freely. Anyone else got past
+    // the account constraints only by passing their Delegation PDA, so the
+    // withdrawal must fit in what's left of the current period's allowance.
+    let mut remaining_allowance = None;
+    if vault.is_authorized(&ctx.accounts.user.key()) {
+        // Instant withdrawals are only allowed while the time lock is off
+        require!(
+            ctx.accounts.config.withdrawal_delay == 0,
+            VaultError::WithdrawalDelayActive
+        );
+    } else {
+        let delegation = ctx.accounts.delegation.as_mut().ok_or(VaultError::Unauthorized)?;
+
+        // A grant dies with any ownership change, even one back to the granter
+        require!(delegation.owner_epoch == vault.owner_epoch, VaultError::Unauthorized);
+        require!(!delegation.is_expired(current_time), VaultError::DelegationExpired);
+        require!(
+            !delegation.is_pending_activation(current_time),
+            VaultError::DelegationNotActive
+        );
+
+        delegation.refill(current_time);
+        require!(
+            amount <= delegation.allowance,
+            VaultError::DelegationLimitExceeded
+        );
+        delegation.allowance = delegation.allowance
+            .checked_sub(amount)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+
+        remaining_allowance = Some(delegation.allowance);
+        msg!(
+            "Delegated withdrawal: {} lamports left (refills {} every {}s)",
+            delegation.allowance,
+            delegation.limit_per_period,
+            delegation.period_duration
+        );
+    }
 
     // CONCEPT: Business Logic - Can only withdraw unstaked funds
-    // Available balance = total_deposited - staked_amount
+    // Available balance = total_deposited - staked_amount - pending_unstake - queued_withdrawal
     let available_balance = vault.get_available_balance();
 
     require!(
@@ -20,34 +64,21 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         VaultError::InsufficientFunds
     );
 
-    // Check vault PDA has enough lamports
-    let vault_lamports = vault.to_account_info().lamports();
+    // Check vault PDA has enough lamports ABOVE its rent-exempt minimum
+    // The rent deposit belongs to the account itself, not to the user's balance
+    let vault_info = vault.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let withdrawable_lamports = vault_info.lamports().saturating_sub(rent_minimum);
     require!(
-        vault_lamports >= amount,
+        withdrawable_lamports >= amount,
         VaultError::InsufficientFunds
     );
 
-    // CONCEPT: PDA Signing
-    // Reference: https://www.anchor-lang.com/docs/pdas
-    let seeds = &[
-        b"vault",
-        vault.owner.as_ref(),
-        &[vault.bump],
-    ];
-    let signer_seeds = &[&seeds[..]];
-
-    let accounts = Transfer {
-        from: ctx.accounts.vault.to_account_info(),
-        to: ctx.accounts.user.to_account_info(),
-    };
-
-    let cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.system_program.to_account_info(),
-        accounts,
-        signer_seeds,
-    );
-
-    transfer(cpi_context, amount)?;
+    // CONCEPT: Direct Lamport Manipulation
+    // The vault PDA holds data and is owned by THIS program, so the System
+    // Program cannot debit it. Since we own it, we move lamports directly.
+    vault.sub_lamports(amount)?;
+    ctx.accounts.user.add_lamports(amount)?;
 
     // Update vault state
     vault.total_deposited = vault.total_deposited
@@ -55,6 +86,15 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         .ok_or(VaultError::ArithmeticOverflow)?;
 
     msg!("Withdrawn {} lamports. Total deposited: {}", amount, vault.total_deposited);
+    emit!(Withdrawn {
+        owner: vault.owner,
+        vault: vault.key(),
+        authority: ctx.accounts.user.key(),
+        amount,
+        remaining_allowance,
+        total_deposited: vault.total_deposited,
+        timestamp: current_time,
+    });
     Ok(())
 }
 
@@ -62,27 +102,51 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
 pub struct Withdraw<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.owner.as_ref()],
+        seeds = [b"vault", vault.creator.as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_authorized(&user.key()) || delegation.is_some() @ VaultError::Unauthorized
     )]
     pub vault: Account<'info, Vault>,
 
-    #[account(mut)]
-    pub user: Signer<'info>,
+    // CONCEPT: Optional Accounts
+    // Reference: https://www.anchor-lang.com/docs/references/account-types
+    // Only a spending-limited delegate passes its Delegation; everyone else
+    // leaves it out (null). The seeds tie it to this vault AND this signer,
+    // so nobody can spend someone else's allowance.
+    #[account(
+        mut,
+        seeds = [b"delegation", vault.key().as_ref(), user.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
 
-    /// CHECK: Verified through has_one constraint
-    pub owner: UncheckedAccount<'info>,
+    // Read for the withdrawal delay
+    #[account(
+        seeds = [b"staking_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakingConfig>,
 
-    pub system_program: Program<'info, System>,
+    // Owner, delegate or limited delegate - receives the withdrawn lamports
+    #[account(mut)]
+    pub user: Signer<'info>,
 }
 
+// CONCEPT: Authority Check
+// The vault address is derived from vault.creator, so anyone can pass in
+// ANY vault. The constraint on `vault` is what stops an attacker: the
+// signer must be the stored owner or the delegate the owner chose.
+// Checking has_one against an unsigned `owner` account is NOT enough -
+// an attacker could simply pass the victim's pubkey.
+
 // CONCEPT: Withdraw vs Unstake
 // - Withdraw: removes unstaked funds from vault to wallet
-// - Unstake: moves funds from "staked" to "unstaked" (with potential rewards)
+// - Request Unstake: moves funds from "staked" to "pending" (rewards paid now)
+// - Complete Unstake: moves funds from "pending" to "unstaked" after the cooldown
 //
 // User flow:
 // 1. Deposit SOL → vault (unstaked)
 // 2. Stake → locks funds (staked)
-// 3. Unstake → unlocks funds + rewards (unstaked)
-// 4. Withdraw → removes funds from vault to wallet
+// 3. Request Unstake → starts unbonding + pays rewards (pending)
+// 4. Complete Unstake → after the cooldown (unstaked)
+// 5. Withdraw → removes funds from vault to wallet
@@ -5,6 +5,8 @@
 // - state.rs: Account structs
 // - errors.rs: Custom errors
 // - constants.rs: Configuration values
+// - rewards.rs: Reward math shared by the instructions
+// - events.rs: Structured events emitted for indexers
 // - instructions/: Each instruction in its own file
 //
 // Benefits:
@@ -20,10 +22,13 @@ use anchor_lang::prelude::*;
 mod state;
 mod errors;
 mod constants;
+mod rewards;
+mod events;
 mod instructions;
 
 // Make them available to use in this file
 use instructions::*;
+use state::LockTier;
 
 // CONCEPT: Program ID
 // This will be different from day-1 since it's a new program
@@ -47,23 +52,276 @@ pub mod vault_staking {
         instructions::deposit::deposit(ctx, amount)
     }
 
-    /// Withdraws unstaked SOL from vault to user wallet
+    /// Deposits SOL from any payer into `owner`'s vault (payroll, gifts)
+    pub fn deposit_for(ctx: Context<DepositFor>, owner: Pubkey, amount: u64) -> Result<()> {
+        instructions::deposit_for::deposit_for(ctx, owner, amount)
+    }
+
+    /// Withdraws unstaked SOL from vault to the signer's wallet
+    /// Signer must be the vault owner or its delegate, or hold a Delegation
+    /// (passed as `delegation`) with enough allowance left
+    /// The owner and delegate must use the withdrawal queue while config.withdrawal_delay > 0
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         instructions::withdraw::withdraw(ctx, amount)
     }
 
+    /// Moves unstaked SOL from one vault to another in a single instruction
+    /// Signer must be the source vault's owner or delegate; blocked while config.withdrawal_delay > 0
+    pub fn transfer_between_vaults(ctx: Context<TransferBetweenVaults>, amount: u64) -> Result<()> {
+        instructions::transfer_between_vaults::transfer_between_vaults(ctx, amount)
+    }
+
+    /// Reserves `amount` for a withdrawal that unlocks after config.withdrawal_delay
+    /// Queuing more adds to the reserved amount and restarts the timer
+    pub fn queue_withdraw(ctx: Context<QueueWithdraw>, amount: u64) -> Result<()> {
+        instructions::queue_withdraw::queue_withdraw(ctx, amount)
+    }
+
+    /// Sends the whole queued amount to the signer once it has unlocked
+    pub fn execute_withdraw(ctx: Context<ExecuteWithdraw>) -> Result<()> {
+        instructions::execute_withdraw::execute_withdraw(ctx)
+    }
+
+    /// Releases the queued amount back to the available balance
+    pub fn cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+        instructions::cancel_withdraw::cancel_withdraw(ctx)
+    }
+
+    /// Closes the vault and returns all its lamports (balance + rent) to the owner
+    /// Requires nothing staked or pending unstake; owner only
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        instructions::close_vault::close_vault(ctx)
+    }
+
     // DAY 2 INSTRUCTIONS (New - Staking)
 
-    /// Stakes deposited funds to earn rewards
-    /// Must be staked for at least 60 seconds to earn 10% rewards
-    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
-        instructions::stake::stake(ctx, amount)
+    /// Stakes deposited funds in a new stake position to earn rewards
+    /// Rewards accrue at the configured APR x the lock tier multiplier once the minimum duration is met
+    pub fn stake(ctx: Context<Stake>, amount: u64, lock_tier: LockTier) -> Result<()> {
+        instructions::stake::stake(ctx, amount, lock_tier)
+    }
+
+    /// Unstakes `amount` from one position and distributes its rewards if eligible
+    /// The principal becomes pending until the unbonding cooldown ends
+    /// The rest of the position stays staked with its original timestamp
+    /// Rewards: configured APR on the unstaked amount over the stake duration, 0 before the cliff
+    /// Before the cliff, the configured early unstake penalty (if any) is taken from principal
+    /// Rewards are paid from the reward pool (partially if the pool is short)
+    /// Before lock_end, requires accept_early_exit_penalty (no rewards, principal penalty)
+    pub fn request_unstake(
+        ctx: Context<RequestUnstake>,
+        amount: u64,
+        accept_early_exit_penalty: bool,
+    ) -> Result<()> {
+        instructions::request_unstake::request_unstake(ctx, amount, accept_early_exit_penalty)
+    }
+
+    /// Releases pending unstaked funds once the unbonding cooldown has passed
+    pub fn complete_unstake(ctx: Context<CompleteUnstake>) -> Result<()> {
+        instructions::complete_unstake::complete_unstake(ctx)
+    }
+
+    /// Pays a position's accrued rewards into the vault without unstaking
+    /// Resets the accrual checkpoint; the principal stays staked
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        instructions::claim_rewards::claim_rewards(ctx)
+    }
+
+    // COMPOUNDING INSTRUCTIONS
+
+    /// Owner opts the vault in or out of permissionless auto-compounding
+    pub fn set_auto_compound(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+        instructions::set_auto_compound::set_auto_compound(ctx, enabled)
+    }
+
+    /// Permissionless crank: re-stakes a position's accrued rewards
+    /// The caller receives a tip from the reward pool (config.compound_tip_basis_points)
+    pub fn compound(ctx: Context<Compound>) -> Result<()> {
+        instructions::compound::compound(ctx)
+    }
+
+    // AUTHORITY INSTRUCTIONS
+
+    /// Authorizes a delegate to withdraw, stake and unstake for the owner
+    /// Pass None to revoke the current delegate
+    pub fn set_delegate(ctx: Context<SetDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+        instructions::set_delegate::set_delegate(ctx, delegate)
+    }
+
+    /// Grants `delegate` the right to withdraw up to `limit_per_period` lamports
+    /// every `period_duration` seconds, until `expires_at`
+    /// The grant becomes usable after config.withdrawal_delay
+    pub fn grant_delegation(
+        ctx: Context<GrantDelegation>,
+        delegate: Pubkey,
+        limit_per_period: u64,
+        period_duration: i64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::grant_delegation::grant_delegation(
+            ctx,
+            delegate,
+            limit_per_period,
+            period_duration,
+            expires_at,
+        )
+    }
+
+    /// Revokes a spending-limited delegation; its rent goes back to the owner
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::revoke_delegation::revoke_delegation(ctx)
+    }
+
+    /// Step 1 of an ownership transfer: the owner proposes a new owner
+    /// Pass None to cancel a pending proposal; it can be accepted after config.withdrawal_delay
+    pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Option<Pubkey>) -> Result<()> {
+        instructions::propose_owner::propose_owner(ctx, new_owner)
+    }
+
+    /// Step 2: once the delay has passed, the proposed owner signs to take over the vault
+    /// The vault keeps its address, balances and positions; the delegate is cleared
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        instructions::accept_owner::accept_owner(ctx)
+    }
+
+    // DEAD-MAN'S SWITCH INSTRUCTIONS
+
+    /// Names a beneficiary who may claim the vault after `inactivity_window` seconds
+    /// without owner activity; None removes the beneficiary
+    pub fn set_beneficiary(
+        ctx: Context<SetBeneficiary>,
+        beneficiary: Option<Pubkey>,
+        inactivity_window: i64,
+    ) -> Result<()> {
+        instructions::set_beneficiary::set_beneficiary(ctx, beneficiary, inactivity_window)
+    }
+
+    /// Owner proves they're still active without moving funds
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        instructions::heartbeat::heartbeat(ctx)
+    }
+
+    /// Beneficiary claims the unstaked balance and ownership of an inactive vault
+    pub fn claim_inheritance(ctx: Context<ClaimInheritance>) -> Result<()> {
+        instructions::claim_inheritance::claim_inheritance(ctx)
+    }
+
+    // MULTISIG INSTRUCTIONS
+
+    /// Creates an M-of-N multisig vault: `threshold` of `signers` must approve withdrawals
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_multisig::create_multisig(ctx, signers, threshold)
+    }
+
+    /// Deposits SOL into a multisig vault (anyone can pay in)
+    pub fn deposit_multisig(ctx: Context<DepositMultisig>, amount: u64) -> Result<()> {
+        instructions::deposit_multisig::deposit_multisig(ctx, amount)
+    }
+
+    /// A multisig signer proposes sending `amount` to `recipient` (counts as their approval)
+    pub fn propose_withdrawal(
+        ctx: Context<ProposeWithdrawal>,
+        amount: u64,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_withdrawal::propose_withdrawal(ctx, amount, recipient)
+    }
+
+    /// A multisig signer approves a pending proposal
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal::approve_proposal(ctx)
+    }
+
+    /// Executes a proposal once it has `threshold` approvals
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::execute_proposal(ctx)
+    }
+
+    // REWARD POOL INSTRUCTIONS
+
+    /// Creates the program-wide reward pool; the upgrade authority becomes its admin
+    pub fn initialize_reward_pool(ctx: Context<InitializeRewardPool>) -> Result<()> {
+        instructions::initialize_reward_pool::initialize_reward_pool(ctx)
+    }
+
+    /// Admin deposits SOL into the reward pool to fund staking rewards
+    pub fn fund_reward_pool(ctx: Context<FundRewardPool>, amount: u64) -> Result<()> {
+        instructions::fund_reward_pool::fund_reward_pool(ctx, amount)
+    }
+
+    // STAKE POOL INSTRUCTIONS
+
+    /// Creates the program-wide stake pool that emits `emission_rate` lamports/s
+    /// to all stakers pro rata; only the upgrade authority may create it
+    pub fn initialize_stake_pool(ctx: Context<InitializeStakePool>, emission_rate: u64) -> Result<()> {
+        instructions::initialize_stake_pool::initialize_stake_pool(ctx, emission_rate)
+    }
+
+    /// Admin adds SOL to the stake pool's emission budget
+    pub fn fund_stake_pool(ctx: Context<FundStakePool>, amount: u64) -> Result<()> {
+        instructions::fund_stake_pool::fund_stake_pool(ctx, amount)
+    }
+
+    /// Admin changes the stake pool's emission rate (lamports/s)
+    /// Emissions up to now are settled at the old rate first
+    pub fn update_stake_pool_rate(ctx: Context<UpdateStakePoolRate>, emission_rate: u64) -> Result<()> {
+        instructions::update_stake_pool_rate::update_stake_pool_rate(ctx, emission_rate)
+    }
+
+    /// Pays the vault its share of stake pool emissions (reward_per_share - reward_debt)
+    pub fn harvest_pool_rewards(ctx: Context<HarvestPoolRewards>) -> Result<()> {
+        instructions::harvest_pool_rewards::harvest_pool_rewards(ctx)
+    }
+
+    // CONFIG INSTRUCTIONS
+
+    /// Creates the program-wide staking config with default parameters
+    /// Only the upgrade authority may call it; it becomes the config admin
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx)
+    }
+
+    /// Admin updates the minimum stake duration, APR, compound tip, unbonding period,
+    /// early unstake penalty and withdrawal delay
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        min_stake_duration: i64,
+        reward_rate_basis_points: u64,
+        compound_tip_basis_points: u64,
+        unbonding_period: i64,
+        early_unstake_penalty_basis_points: u64,
+        withdrawal_delay: i64,
+    ) -> Result<()> {
+        instructions::update_config::update_config(
+            ctx,
+            min_stake_duration,
+            reward_rate_basis_points,
+            compound_tip_basis_points,
+            unbonding_period,
+            early_unstake_penalty_basis_points,
+            withdrawal_delay,
+        )
     }
 
-    /// Unstakes funds and distributes rewards if eligible
-    /// Rewards: 10% if staked for 60+ seconds, 0% if less
-    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
-        instructions::unstake::unstake(ctx)
+    /// Admin updates the lock tier reward multipliers and the early exit penalty
+    pub fn update_lock_tiers(
+        ctx: Context<UpdateLockTiers>,
+        one_day_basis_points: u64,
+        seven_days_basis_points: u64,
+        thirty_days_basis_points: u64,
+        early_exit_penalty_basis_points: u64,
+    ) -> Result<()> {
+        instructions::update_lock_tiers::update_lock_tiers(
+            ctx,
+            one_day_basis_points,
+            seven_days_basis_points,
+            thirty_days_basis_points,
+            early_exit_penalty_basis_points,
+        )
     }
 }
 
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use crate::state::{Vault, RewardPool, StakePool};
+use crate::errors::VaultError;
+use crate::constants::*;
+
+// CONCEPT: Shared Reward Logic
+// Several instructions settle rewards (request_unstake, claim_rewards, compound), so the math
+// and the pool payout live here instead of being copied into each handler.
+// Every path that pays rewards then uses exactly the same formula.
+
+/// Calculates the APR reward earned by `amount` lamports over `duration` seconds,
+/// scaled by the lock tier multiplier (basis points, 10000 = 1x)
+pub fn calculate_reward(
+    amount: u64,
+    reward_rate_basis_points: u64,
+    multiplier_basis_points: u64,
+    duration: i64,
+) -> Result<u64> {
+    // CONCEPT: APR Reward Calculation with Fixed-Point Math
+    // Formula: reward = amount * rate * multiplier * duration / (divisor * divisor * seconds_per_year)
+    // Example: 1000 SOL at 1000 bps (10% APR), 1x, for half a year = 50 SOL
+    // All intermediate values are u128 so the multiplications cannot overflow
+    let duration = u128::try_from(duration).map_err(|_| VaultError::ArithmeticOverflow)?;
+    let reward = (amount as u128)
+        .checked_mul(reward_rate_basis_points as u128)
+        .ok_or(VaultError::ArithmeticOverflow)?
+        .checked_mul(multiplier_basis_points as u128)
+        .ok_or(VaultError::ArithmeticOverflow)?
+        .checked_mul(duration)
+        .ok_or(VaultError::ArithmeticOverflow)?
+        .checked_div(
+            (BASIS_POINTS_DIVISOR as u128)
+                .checked_mul(BASIS_POINTS_DIVISOR as u128)
+                .ok_or(VaultError::ArithmeticOverflow)?
+                .checked_mul(SECONDS_PER_YEAR as u128)
+                .ok_or(VaultError::ArithmeticOverflow)?,
+        )
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    Ok(u64::try_from(reward).map_err(|_| VaultError::ArithmeticOverflow)?)
+}
+
+/// Lamports the reward pool can pay out while staying rent-exempt
+pub fn reward_pool_available(reward_pool: &Account<RewardPool>) -> Result<u64> {
+    let pool_info = reward_pool.to_account_info();
+    let pool_rent_minimum = Rent::get()?.minimum_balance(pool_info.data_len());
+    Ok(pool_info.lamports().saturating_sub(pool_rent_minimum))
+}
+
+/// Moves `amount` lamports of rewards from the pool into the vault
+/// and credits them to the vault's available balance
+pub fn pay_reward<'info>(
+    reward_pool: &mut Account<'info, RewardPool>,
+    vault: &mut Account<'info, Vault>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    // Both accounts are owned by this program, so lamports move directly
+    reward_pool.sub_lamports(amount)?;
+    vault.add_lamports(amount)?;
+
+    reward_pool.total_paid = reward_pool.total_paid
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.total_deposited = vault.total_deposited
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// CONCEPT: Why Use u128 for Calculation?
+// amount * rate * multiplier * duration can easily exceed u64::MAX
+// Example: 1_000_000 SOL (1e15 lamports) * 1000 bps * 15000 bps * 1 year (3.15e7 s) ≈ 5e29
+// u128 holds up to ~3.4e38, so the product fits; we divide last to keep precision.
+// u64::try_from turns a (theoretical) oversized result into an error, not a silent truncation.
+
+// CONCEPT: Stake Pool Accounting (MasterChef)
+// The helpers below keep the global reward_per_share index in sync.
+// Every instruction that changes vault.staked_amount follows the same order:
+// 1. update_stake_pool  - emit everything owed up to now
+//    (then enroll_in_stake_pool, for vaults that staked before the pool existed)
+// 2. settle_pool_reward - pay the vault what it earned at its OLD stake
+// 3. change staked_amount (and stake_pool.total_staked)
+// 4. sync_reward_debt   - start the vault's next period from the current index
+
+/// Emits the stake pool budget accrued since the last update into reward_per_share
+pub fn update_stake_pool(stake_pool: &mut Account<StakePool>, current_time: i64) -> Result<()> {
+    let elapsed = current_time.saturating_sub(stake_pool.last_update_timestamp);
+    stake_pool.last_update_timestamp = current_time;
+
+    // With nobody staked there is no one to emit to; the budget waits
+    if elapsed <= 0 || stake_pool.total_staked == 0 {
+        return Ok(());
+    }
+
+    // Emissions stop once the funded budget runs out
+    let emitted = (elapsed as u128)
+        .checked_mul(stake_pool.emission_rate as u128)
+        .ok_or(VaultError::ArithmeticOverflow)?
+        .min(stake_pool.undistributed as u128);
+
+    stake_pool.reward_per_share = stake_pool.reward_per_share
+        .checked_add(
+            emitted
+                .checked_mul(REWARD_PER_SHARE_PRECISION)
+                .ok_or(VaultError::ArithmeticOverflow)?
+                .checked_div(stake_pool.total_staked as u128)
+                .ok_or(VaultError::ArithmeticOverflow)?,
+        )
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    // emitted <= undistributed, so this always fits in a u64
+    stake_pool.undistributed = stake_pool.undistributed
+        .checked_sub(emitted as u64)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Stake pool rewards earned by `staked_amount` at the current index
+fn accumulated_pool_reward(stake_pool: &StakePool, staked_amount: u64) -> Result<u128> {
+    Ok((staked_amount as u128)
+        .checked_mul(stake_pool.reward_per_share)
+        .ok_or(VaultError::ArithmeticOverflow)?
+        .checked_div(REWARD_PER_SHARE_PRECISION)
+        .ok_or(VaultError::ArithmeticOverflow)?)
+}
+
+/// Pays the vault its pending stake pool rewards; returns the amount paid
+/// Call update_stake_pool first and sync_reward_debt after changing the stake
+pub fn settle_pool_reward<'info>(
+    stake_pool: &mut Account<'info, StakePool>,
+    vault: &mut Account<'info, Vault>,
+) -> Result<u64> {
+    let pending = accumulated_pool_reward(stake_pool, vault.staked_amount)?
+        .checked_sub(vault.reward_debt)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    let pending = u64::try_from(pending).map_err(|_| VaultError::ArithmeticOverflow)?;
+
+    if pending > 0 {
+        // Rounding always goes down, so the pool holds every emitted lamport
+        stake_pool.sub_lamports(pending)?;
+        vault.add_lamports(pending)?;
+
+        stake_pool.total_paid = stake_pool.total_paid
+            .checked_add(pending)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        vault.total_deposited = vault.total_deposited
+            .checked_add(pending)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+    }
+
+    vault.reward_debt = accumulated_pool_reward(stake_pool, vault.staked_amount)?;
+    Ok(pending)
+}
+
+/// Counts a vault that staked before the stake pool existed in total_staked
+/// Call after update_stake_pool: its old stake only earns emissions from now on
+pub fn enroll_in_stake_pool(stake_pool: &mut Account<StakePool>, vault: &mut Vault) -> Result<()> {
+    if vault.in_stake_pool {
+        return Ok(());
+    }
+
+    stake_pool.total_staked = stake_pool.total_staked
+        .checked_add(vault.staked_amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    vault.in_stake_pool = true;
+    sync_reward_debt(stake_pool, vault)
+}
+
+/// Marks everything up to the current index as accounted for the vault's (new) stake
+pub fn sync_reward_debt(stake_pool: &StakePool, vault: &mut Vault) -> Result<()> {
+    vault.reward_debt = accumulated_pool_reward(stake_pool, vault.staked_amount)?;
+    Ok(())
+}
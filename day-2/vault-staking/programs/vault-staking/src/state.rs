@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
 
 // CONCEPT: State Module
 // Separating account structs into their own module makes the code cleaner
@@ -17,44 +18,354 @@ pub struct Vault {
 
     // NEW FIELDS (for Day 2 - Staking)
     pub total_deposited: u64,    // 8 bytes - total SOL ever deposited
-    pub staked_amount: u64,      // 8 bytes - currently staked amount
-    pub stake_timestamp: i64,    // 8 bytes - when staking started (Unix timestamp)
-    pub is_staked: bool,         // 1 byte - is currently staked?
+    pub staked_amount: u64,      // 8 bytes - sum of all open stake positions
+    pub next_position_index: u64, // 8 bytes - index for the next StakePosition PDA
 
-    // Total: 32 + 1 + 8 + 8 + 8 + 1 = 58 bytes
-    // With discriminator: 58 + 8 = 66 bytes
+    // AUTHORITY
+    pub delegate: Option<Pubkey>, // 33 bytes - optional key allowed to act for the owner
+    pub open_delegations: u64,   // 8 bytes - Delegation PDAs not revoked yet (close_vault needs 0)
+
+    // COMPOUNDING
+    pub auto_compound: bool,     // 1 byte - lets anyone crank `compound` on this vault
+
+    // UNBONDING
+    pub pending_unstake: u64,    // 8 bytes - principal waiting out the cooldown
+    pub unbonding_end: i64,      // 8 bytes - when pending_unstake can be completed
+
+    // POOLED REWARDS
+    pub reward_debt: u128,       // 16 bytes - stake pool rewards already accounted for
+    pub in_stake_pool: bool,     // 1 byte - staked_amount is counted in stake_pool.total_staked
+
+    // WITHDRAWAL QUEUE
+    pub queued_withdrawal: u64,  // 8 bytes - lamports reserved for a time-locked withdrawal
+    pub withdrawal_unlock_time: i64, // 8 bytes - when the queued withdrawal can be executed
+
+    // OWNERSHIP
+    pub creator: Pubkey,         // 32 bytes - PDA seed; the first owner, never changes
+    pub pending_owner: Option<Pubkey>, // 33 bytes - proposed new owner awaiting accept_owner
+    pub owner_transfer_unlock_time: i64, // 8 bytes - when pending_owner may accept
+    pub owner_epoch: u64,        // 8 bytes - bumped on every ownership change, retires older Delegations
+
+    // DEAD-MAN'S SWITCH
+    pub beneficiary: Option<Pubkey>, // 33 bytes - may claim the vault once the owner goes quiet
+    pub inactivity_window: i64,  // 8 bytes - seconds of owner silence before the claim opens
+    pub last_owner_activity: i64, // 8 bytes - last deposit/withdraw/stake/heartbeat by the owner
+
+    // Total: 32 + 1 + 8 + 8 + 8 + 33 + 8 + 1 + 8 + 8 + 16 + 1 + 8 + 8 + 32 + 33 + 8 + 8 + 33 + 8 + 8 = 278 bytes
+    // With discriminator: 278 + 8 = 286 bytes
 }
 
 impl Vault {
     // CONCEPT: Helper Methods on Account Structs
     // We can add methods to make the code more readable
 
-    /// Calculates the staking duration in seconds
-    pub fn get_stake_duration(&self, current_time: i64) -> i64 {
-        if !self.is_staked {
-            return 0;
+    /// Checks if `signer` may move funds: the owner or the authorized delegate
+    pub fn is_authorized(&self, signer: &Pubkey) -> bool {
+        self.owner == *signer || self.delegate == Some(*signer)
+    }
+
+    /// Resets the dead-man's switch - only the owner's own actions count
+    pub fn record_owner_activity(&mut self, signer: &Pubkey, current_time: i64) {
+        if self.owner == *signer {
+            self.last_owner_activity = current_time;
         }
-        current_time.saturating_sub(self.stake_timestamp)
     }
 
-    /// Checks if the minimum stake duration has been met
-    pub fn has_met_min_duration(&self, current_time: i64, min_duration: i64) -> bool {
-        self.get_stake_duration(current_time) >= min_duration
+    /// Checks if the owner has been silent for the whole inactivity window
+    /// (and never for less than `min_window`, the withdrawal delay)
+    pub fn is_owner_inactive(&self, current_time: i64, min_window: i64) -> bool {
+        current_time.saturating_sub(self.last_owner_activity)
+            >= self.inactivity_window.max(min_window)
     }
 
     /// Gets the available (unstaked) balance
-    /// Available balance = total deposited - currently staked
+    /// Available balance = total deposited - currently staked - pending unstake - queued withdrawal
     pub fn get_available_balance(&self) -> u64 {
-        self.total_deposited.saturating_sub(self.staked_amount)
+        self.total_deposited
+            .saturating_sub(self.staked_amount)
+            .saturating_sub(self.pending_unstake)
+            .saturating_sub(self.queued_withdrawal)
     }
 }
 
 // CONCEPT: Account Space Calculation
-// InitSpace automatically calculates: 32 + 1 + 8 + 8 + 8 + 1 = 58 bytes
+// InitSpace automatically calculates the Vault: 32 + 1 + 8 + 8 + 8 + 33 + 8 + 1 + 8 + 8 + 16 + 1 + 8 + 8 + 32 + 33 + 8 + 8 + 33 + 8 + 8 = 278 bytes
 // Anchor adds 8-byte discriminator automatically
-// Total account size = 66 bytes
+// Total account size = 286 bytes
 
 // CONCEPT: Saturating Math
 // Reference: https://doc.rust-lang.org/std/primitive.u64.html#method.saturating_sub
 // saturating_sub prevents underflow - if result would be negative, returns 0
 // This is safer than regular subtraction which could panic
+
+// CONCEPT: Stake Positions
+// Each stake is its own PDA with its own amount and start time, so a user
+// can keep adding to their stake without resetting the timer on older stakes.
+// Seeds: [b"stake_position", vault, index.to_le_bytes()]
+// The index comes from vault.next_position_index and only ever increases.
+// The cliff is measured from stake_timestamp; rewards accrue from
+// last_claim_timestamp, which claim_rewards moves forward.
+
+// CONCEPT: Enums in Accounts and Instructions
+// Reference: https://www.anchor-lang.com/docs/idl
+// A unit-only enum is stored as 1 byte and shows up in the IDL, so clients
+// pass e.g. `{ sevenDays: {} }` and can't pick a tier that doesn't exist.
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum LockTier {
+    None,        // no lock, 1x
+    OneDay,      // 1 day lock, 1.1x
+    SevenDays,   // 7 day lock, 1.25x
+    ThirtyDays,  // 30 day lock, 1.5x
+}
+
+impl LockTier {
+    /// How long funds in this tier are locked, in seconds
+    pub fn lock_duration(&self) -> i64 {
+        match self {
+            LockTier::None => 0,
+            LockTier::OneDay => LOCK_DURATION_ONE_DAY,
+            LockTier::SevenDays => LOCK_DURATION_SEVEN_DAYS,
+            LockTier::ThirtyDays => LOCK_DURATION_THIRTY_DAYS,
+        }
+    }
+
+    /// Reward multiplier in basis points (10000 = 1x), as currently set in the config
+    pub fn multiplier_basis_points(&self, config: &StakingConfig) -> u64 {
+        match self {
+            LockTier::None => LOCK_MULTIPLIER_NONE,
+            LockTier::OneDay => config.lock_multiplier_one_day_basis_points,
+            LockTier::SevenDays => config.lock_multiplier_seven_days_basis_points,
+            LockTier::ThirtyDays => config.lock_multiplier_thirty_days_basis_points,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakePosition {
+    pub vault: Pubkey,           // 32 bytes - vault this position belongs to
+    pub index: u64,              // 8 bytes - position number within the vault
+    pub amount: u64,             // 8 bytes - lamports staked in this position
+    pub stake_timestamp: i64,    // 8 bytes - when this position was opened
+    pub last_claim_timestamp: i64, // 8 bytes - rewards accrue from here (accrual checkpoint)
+    pub lock_tier: LockTier,     // 1 byte - chosen lock period / multiplier
+    pub lock_end: i64,           // 8 bytes - principal is locked until this time
+    pub payer: Pubkey,           // 32 bytes - paid the rent; gets it back when the position closes
+    pub bump: u8,                // 1 byte - PDA bump seed
+
+    // Total: 32 + 8 + 8 + 8 + 8 + 1 + 8 + 32 + 1 = 106 bytes
+    // With discriminator: 106 + 8 = 114 bytes
+}
+
+impl StakePosition {
+    /// Calculates how long this position has been staked, in seconds
+    pub fn get_stake_duration(&self, current_time: i64) -> i64 {
+        current_time.saturating_sub(self.stake_timestamp)
+    }
+
+    /// Checks if the minimum stake duration has been met
+    pub fn has_met_min_duration(&self, current_time: i64, min_duration: i64) -> bool {
+        self.get_stake_duration(current_time) >= min_duration
+    }
+
+    /// Calculates how long rewards have accrued since the last checkpoint
+    pub fn get_accrual_duration(&self, current_time: i64) -> i64 {
+        current_time.saturating_sub(self.last_claim_timestamp)
+    }
+
+    /// Checks if the principal is still inside its lock period
+    pub fn is_locked(&self, current_time: i64) -> bool {
+        current_time < self.lock_end
+    }
+}
+
+// CONCEPT: Spending-limited Delegation
+// Unlike vault.delegate (full authority), a Delegation only lets one key
+// withdraw up to limit_per_period lamports per period, until expires_at.
+// The allowance refills linearly (limit_per_period every period_duration
+// seconds, capped at limit_per_period) instead of resetting in fixed windows.
+// A fixed window would let the delegate spend the limit just before a reset
+// and again just after it - twice the limit in a few seconds.
+// Seeds: [b"delegation", vault, delegate] - one grant per delegate per vault
+// The seeds don't change when a vault is closed and re-initialized, so
+// vault.open_delegations must be 0 before close_vault - a leftover grant
+// would otherwise come back to life in the new vault.
+
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    pub vault: Pubkey,           // 32 bytes - vault the grant draws from
+    pub delegate: Pubkey,        // 32 bytes - key allowed to withdraw
+    pub granted_by: Pubkey,      // 32 bytes - owner who granted it (gets the rent back on revoke)
+    pub owner_epoch: u64,        // 8 bytes - vault.owner_epoch at grant time; any ownership change retires the grant
+    pub limit_per_period: u64,   // 8 bytes - max lamports withdrawn per period
+    pub period_duration: i64,    // 8 bytes - period length in seconds
+    pub last_refill: i64,        // 8 bytes - when the allowance was last refilled
+    pub allowance: u64,          // 8 bytes - lamports the delegate may withdraw right now
+    pub expires_at: i64,         // 8 bytes - grant is unusable from this time on
+    pub active_from: i64,        // 8 bytes - grant is unusable until the withdrawal delay has passed
+    pub bump: u8,                // 1 byte - PDA bump seed
+
+    // Total: 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 = 153 bytes
+    // With discriminator: 153 + 8 = 161 bytes
+}
+
+impl Delegation {
+    /// Checks if the grant can no longer be used
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.expires_at
+    }
+
+    /// Checks if the grant is still waiting out the withdrawal delay
+    pub fn is_pending_activation(&self, current_time: i64) -> bool {
+        current_time < self.active_from
+    }
+
+    /// Credits the allowance for the time since last_refill, capped at the limit
+    pub fn refill(&mut self, current_time: i64) {
+        let elapsed = current_time.saturating_sub(self.last_refill);
+        if elapsed <= 0 {
+            return;
+        }
+
+        // u128 so limit_per_period * elapsed can't overflow
+        let limit = self.limit_per_period as u128;
+        let refilled = (limit * elapsed as u128)
+            .checked_div(self.period_duration as u128)
+            .unwrap_or(0);
+        let allowance = (self.allowance as u128 + refilled).min(limit);
+
+        // Only move the clock once something was credited (or the allowance
+        // is full) - otherwise frequent calls would round every refill to 0
+        if refilled > 0 || allowance == limit {
+            self.allowance = allowance as u64;
+            self.last_refill = current_time;
+        }
+    }
+}
+
+// CONCEPT: M-of-N Multisig Vault
+// A vault with no single owner: a fixed set of signers and a threshold.
+// Funds only leave through a Proposal that `threshold` signers approved,
+// so no single (hot) key can drain the treasury.
+// Seeds: [b"multisig", creator] - the creator only pays for the account;
+// it has no special rights unless it is also in `signers`.
+
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigVault {
+    pub creator: Pubkey,         // 32 bytes - PDA seed
+    pub bump: u8,                // 1 byte - PDA bump seed
+    #[max_len(10)]
+    pub signers: Vec<Pubkey>,    // 4 + 32 * 10 bytes - keys that may propose and approve
+    pub threshold: u8,           // 1 byte - approvals needed to execute a proposal
+    pub total_deposited: u64,    // 8 bytes - lamports held for the signers (rent excluded)
+    pub next_proposal_index: u64, // 8 bytes - index for the next Proposal PDA
+
+    // Total: 32 + 1 + 324 + 1 + 8 + 8 = 374 bytes
+    // With discriminator: 374 + 8 = 382 bytes
+}
+
+impl MultisigVault {
+    /// Checks if `key` is one of the multisig's signers
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+// CONCEPT: Proposals
+// Each withdrawal is its own PDA, so several can be in flight at once and
+// every approval is recorded on-chain.
+// Seeds: [b"proposal", multisig, index.to_le_bytes()]
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub multisig: Pubkey,        // 32 bytes - multisig this proposal spends from
+    pub index: u64,              // 8 bytes - proposal number within the multisig
+    pub proposer: Pubkey,        // 32 bytes - signer that created it
+    pub recipient: Pubkey,       // 32 bytes - where the lamports go
+    pub amount: u64,             // 8 bytes - lamports to withdraw
+    #[max_len(10)]
+    pub approvals: Vec<Pubkey>,  // 4 + 32 * 10 bytes - signers that approved
+    pub executed: bool,          // 1 byte - set once the withdrawal happened
+    pub bump: u8,                // 1 byte - PDA bump seed
+
+    // Total: 32 + 8 + 32 + 32 + 8 + 324 + 1 + 1 = 438 bytes
+    // With discriminator: 438 + 8 = 446 bytes
+}
+
+// CONCEPT: Reward Pool Account
+// A program-owned PDA that holds the SOL paid out as staking rewards.
+// Rewards are real lamports moved from this pool into the user's vault,
+// so vault accounting always matches the vault's actual balance.
+// Seeds: [b"reward_pool"] - one pool for the whole program
+
+#[account]
+#[derive(InitSpace)]
+pub struct RewardPool {
+    pub admin: Pubkey,           // 32 bytes - who may fund the pool
+    pub bump: u8,                // 1 byte - PDA bump seed
+    pub total_funded: u64,       // 8 bytes - lamports ever added by the admin
+    pub total_paid: u64,         // 8 bytes - lamports ever paid out as rewards
+    pub total_penalties: u64,    // 8 bytes - lamports received from early-exit/unstake penalties
+
+    // Total: 32 + 1 + 8 + 8 + 8 = 57 bytes
+    // With discriminator: 57 + 8 = 65 bytes
+}
+
+// CONCEPT: Reward-per-Share Accumulator (MasterChef)
+// The stake pool pays a fixed emission budget to ALL stakers, pro rata by
+// stake and time, without looping over vaults:
+// - reward_per_share grows by emitted / total_staked every time the pool is touched
+// - a vault is owed staked_amount * reward_per_share - reward_debt
+// - after settling, reward_debt is reset to staked_amount * reward_per_share
+// reward_per_share is scaled by REWARD_PER_SHARE_PRECISION to keep precision.
+// Seeds: [b"stake_pool"] - one pool for the whole program
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakePool {
+    pub admin: Pubkey,               // 32 bytes - who may fund the pool
+    pub bump: u8,                    // 1 byte - PDA bump seed
+    pub reward_per_share: u128,      // 16 bytes - accumulated rewards per staked lamport (scaled)
+    pub last_update_timestamp: i64,  // 8 bytes - emissions are accounted up to here
+    pub emission_rate: u64,          // 8 bytes - lamports emitted per second
+    pub total_staked: u64,           // 8 bytes - sum of staked_amount across all vaults
+    pub undistributed: u64,          // 8 bytes - funded budget not emitted yet
+    pub total_funded: u64,           // 8 bytes - lamports ever added by the admin
+    pub total_paid: u64,             // 8 bytes - lamports ever paid out to vaults
+
+    // Total: 32 + 1 + 16 + 8 + 8 + 8 + 8 + 8 + 8 = 97 bytes
+    // With discriminator: 97 + 8 = 105 bytes
+}
+
+// CONCEPT: Config Account
+// Staking parameters live on-chain instead of in compile-time constants,
+// so the admin can tune them without redeploying the program.
+// The values in constants.rs are only used as defaults at initialization.
+// Seeds: [b"staking_config"] - one config for the whole program
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakingConfig {
+    pub admin: Pubkey,                   // 32 bytes - who may update the config
+    pub bump: u8,                        // 1 byte - PDA bump seed
+    pub min_stake_duration: i64,         // 8 bytes - vesting cliff in seconds
+    pub reward_rate_basis_points: u64,   // 8 bytes - APR in basis points
+    pub compound_tip_basis_points: u64,  // 8 bytes - share of compounded rewards paid to the cranker
+    pub unbonding_period: i64,           // 8 bytes - cooldown between request_unstake and complete_unstake
+    pub early_unstake_penalty_basis_points: u64, // 8 bytes - principal lost when unstaking before the cliff
+    pub withdrawal_delay: i64,           // 8 bytes - time lock on withdrawals (0 = instant withdraw)
+    pub stake_pool_active: bool,         // 1 byte - set once the stake pool exists; stake changes must then pass it
+
+    // LOCK TIERS
+    pub lock_multiplier_one_day_basis_points: u64,    // 8 bytes - reward multiplier for OneDay locks
+    pub lock_multiplier_seven_days_basis_points: u64, // 8 bytes - reward multiplier for SevenDays locks
+    pub lock_multiplier_thirty_days_basis_points: u64, // 8 bytes - reward multiplier for ThirtyDays locks
+    pub early_exit_penalty_basis_points: u64, // 8 bytes - principal lost when breaking a lock early
+
+    // Total: 32 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 = 114 bytes
+    // With discriminator: 114 + 8 = 122 bytes
+}
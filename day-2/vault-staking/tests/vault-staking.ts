@@ -21,17 +21,113 @@ describe("vault-staking", () => {
   let vaultPda: anchor.web3.PublicKey;
   let vaultBump: number;
 
+  // PDA for the program-wide reward pool (seeds: ["reward_pool"])
+  let rewardPoolPda: anchor.web3.PublicKey;
+  const REWARD_POOL_FUNDING = new BN(1_000_000_000); // 1 SOL
+
+  // PDA for the program-wide staking config (seeds: ["staking_config"])
+  let configPda: anchor.web3.PublicKey;
+
+  // PDA for the program-wide stake pool (seeds: ["stake_pool"])
+  // Left unfunded until the stake pool test, so it pays nothing before that
+  let stakePoolPda: anchor.web3.PublicKey;
+
+  // ProgramData account of the deployed program; its upgrade authority
+  // (the provider wallet under `anchor test`) is the only key allowed to initialize
+  let programDataPda: anchor.web3.PublicKey;
+  const STAKE_POOL_EMISSION_RATE = new BN(1_000_000); // lamports per second
+
+  // Signatures of the admin initializers in before(), checked by the event tests
+  let initConfigSig: string;
+  let initRewardPoolSig: string;
+  let initStakePoolSig: string;
+
   // Constants matching the program
   const MIN_STAKE_DURATION = 60; // seconds
-  const REWARD_RATE_BASIS_POINTS = 1000; // 10%
+  const REWARD_RATE_BASIS_POINTS = 1000; // 10% APR
+  const COMPOUND_TIP_BASIS_POINTS = 50; // 0.5% of compounded rewards
+  const EARLY_EXIT_PENALTY_BASIS_POINTS = 1000; // 10% of released principal
+  const LOCK_MULTIPLIERS = [11000, 12500, 15000]; // OneDay, SevenDays, ThirtyDays (1.1x, 1.25x, 1.5x)
+  const EARLY_UNSTAKE_PENALTY_BASIS_POINTS = 0; // off by default; one test turns it on
+
+  // The program default is a 30s unbonding cooldown. Most tests turn it off
+  // so unstaked funds can be completed right away; the unbonding tests
+  // turn it back on.
+  const TEST_UNBONDING_PERIOD = 0;
+
+  // Withdrawals are instant unless the time lock is on (program default: off).
+  // The withdrawal queue test turns it on and back off.
+  const TEST_WITHDRAWAL_DELAY = 0;
+
+  // Lock tiers are an enum in the program; Anchor encodes variants as objects
+  const NO_LOCK = { none: {} };
+  const SEVEN_DAY_LOCK = { sevenDays: {} };
+  const SEVEN_DAYS_SECONDS = 7 * 24 * 60 * 60;
   const BASIS_POINTS_DIVISOR = 10000;
+  const SECONDS_PER_YEAR = 365 * 24 * 60 * 60;
+
+  // Helper function to derive a stake position PDA
+  // Seeds: ["stake_position", vault, index as u64 little-endian]
+  function findPositionPda(index: number | BN): anchor.web3.PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_position"), vaultPda.toBuffer(), new BN(index).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    return pda;
+  }
+
+  // Helper function to get the PDA the next stake call will create
+  async function nextPositionPda(): Promise<anchor.web3.PublicKey> {
+    const vaultAccount = await program.account.vault.fetch(vaultPda);
+    return findPositionPda(vaultAccount.nextPositionIndex);
+  }
+
+  // Helper function to release any unstaked funds still waiting in the vault
+  async function completePendingUnstake() {
+    const vaultAccount = await program.account.vault.fetch(vaultPda);
+    if (vaultAccount.pendingUnstake.toNumber() > 0) {
+      await program.methods
+        .completeUnstake()
+        .accounts({
+          vault: vaultPda,
+          user: user.publicKey,
+        })
+        .rpc();
+    }
+  }
+
+  // Helper function to fund a keypair so it can pay transaction fees
+  async function airdrop(to: anchor.web3.PublicKey, lamports: number) {
+    const signature = await provider.connection.requestAirdrop(to, lamports);
+    const latestBlockHash = await provider.connection.getLatestBlockhash();
+    await provider.connection.confirmTransaction({
+      blockhash: latestBlockHash.blockhash,
+      lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+      signature,
+    });
+  }
+
+  // Helper function to decode the #[event]s a confirmed transaction emitted
+  async function eventsFrom(signature: string) {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    return Array.from(parser.parseLogs(tx.meta.logMessages));
+  }
 
   // Helper function to wait for a specific duration
   const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
 
-  // Helper function to calculate expected reward
-  function calculateReward(amount: number): number {
-    return Math.floor((amount * REWARD_RATE_BASIS_POINTS) / BASIS_POINTS_DIVISOR);
+  // Helper function to calculate expected reward for a stake duration
+  // Mirrors the program: amount * rate * duration / (divisor * seconds_per_year)
+  function calculateReward(amount: number, durationSeconds: number): number {
+    return new BN(amount)
+      .mul(new BN(REWARD_RATE_BASIS_POINTS))
+      .mul(new BN(durationSeconds))
+      .div(new BN(BASIS_POINTS_DIVISOR).mul(new BN(SECONDS_PER_YEAR)))
+      .toNumber();
   }
 
   before(async () => {
@@ -43,11 +139,119 @@ describe("vault-staking", () => {
       program.programId
     );
 
+    [rewardPoolPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_pool")],
+      program.programId
+    );
+
+    [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("staking_config")],
+      program.programId
+    );
+
+    [stakePoolPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_pool")],
+      program.programId
+    );
+
+    [programDataPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+
+    // CONCEPT: Upgrade Authority Gate
+    // A random key can't front-run the deployer and become the config admin
+    const squatter = anchor.web3.Keypair.generate();
+    await airdrop(squatter.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    try {
+      await program.methods
+        .initializeConfig()
+        .accounts({
+          config: configPda,
+          admin: squatter.publicKey,
+          program: program.programId,
+          programData: programDataPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([squatter])
+        .rpc();
+      expect.fail("Should have thrown NotUpgradeAuthority error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("NotUpgradeAuthority");
+    }
+
+    // CONCEPT: On-chain Config
+    // Starts with the defaults (60s cliff, 10% APR) that the tests below assume
+    initConfigSig = await program.methods
+      .initializeConfig()
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+        program: program.programId,
+        programData: programDataPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    await program.methods
+      .updateConfig(
+        new BN(MIN_STAKE_DURATION),
+        new BN(REWARD_RATE_BASIS_POINTS),
+        new BN(COMPOUND_TIP_BASIS_POINTS),
+        new BN(TEST_UNBONDING_PERIOD),
+        new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+        new BN(TEST_WITHDRAWAL_DELAY)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+
+    // CONCEPT: Funded Reward Pool
+    // Rewards are paid from real lamports, so the pool must be funded first
+    initRewardPoolSig = await program.methods
+      .initializeRewardPool()
+      .accounts({
+        rewardPool: rewardPoolPda,
+        admin: user.publicKey,
+        program: program.programId,
+        programData: programDataPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    await program.methods
+      .fundRewardPool(REWARD_POOL_FUNDING)
+      .accounts({
+        rewardPool: rewardPoolPda,
+        admin: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // CONCEPT: Global Stake Pool
+    // Every stake/unstake settles against it, so it must exist before staking
+    initStakePoolSig = await program.methods
+      .initializeStakePool(STAKE_POOL_EMISSION_RATE)
+      .accounts({
+        stakePool: stakePoolPda,
+        config: configPda,
+        admin: user.publicKey,
+        program: program.programId,
+        programData: programDataPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+
     console.log("\n📋 Test Setup:");
     console.log("   Program ID:", program.programId.toString());
     console.log("   User:", user.publicKey.toString());
     console.log("   Vault PDA:", vaultPda.toString());
     console.log("   Vault Bump:", vaultBump);
+    console.log("   Reward Pool PDA:", rewardPoolPda.toString());
+    console.log("   Config PDA:", configPda.toString());
+    console.log("   Stake Pool PDA:", stakePoolPda.toString());
   });
 
   // ==============================================
@@ -80,8 +284,10 @@ describe("vault-staking", () => {
     expect(vaultAccount.bump).to.equal(vaultBump);
     expect(vaultAccount.totalDeposited.toNumber()).to.equal(0);
     expect(vaultAccount.stakedAmount.toNumber()).to.equal(0);
-    expect(vaultAccount.stakeTimestamp.toNumber()).to.equal(0);
-    expect(vaultAccount.isStaked).to.equal(false);
+    expect(vaultAccount.nextPositionIndex.toNumber()).to.equal(0);
+    expect(vaultAccount.delegate).to.equal(null);
+    expect(vaultAccount.autoCompound).to.equal(false);
+    expect(vaultAccount.pendingUnstake.toNumber()).to.equal(0);
 
     console.log("   ✅ Vault initialized successfully");
     console.log("      Owner:", vaultAccount.owner.toString());
@@ -150,15 +356,20 @@ describe("vault-staking", () => {
     console.log("   📊 Before Stake:");
     console.log("      Total deposited:", vaultAccountBefore.totalDeposited.toNumber());
     console.log("      Staked amount:", vaultAccountBefore.stakedAmount.toNumber());
-    console.log("      Is staked:", vaultAccountBefore.isStaked);
+
+    // Each stake opens a new position at the vault's next index
+    const positionPda = await nextPositionPda();
 
     // Execute stake
     const tx = await program.methods
-      .stake(stakeAmount)
+      .stake(stakeAmount, NO_LOCK)
       .accounts({
         vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
 
@@ -167,13 +378,21 @@ describe("vault-staking", () => {
     // Verify vault account state
     const vaultAccountAfter = await program.account.vault.fetch(vaultPda);
     expect(vaultAccountAfter.stakedAmount.toNumber()).to.equal(stakeAmount.toNumber());
-    expect(vaultAccountAfter.isStaked).to.equal(true);
-    expect(vaultAccountAfter.stakeTimestamp.toNumber()).to.be.greaterThan(0);
+    expect(vaultAccountAfter.nextPositionIndex.toNumber()).to.equal(
+      vaultAccountBefore.nextPositionIndex.toNumber() + 1
+    );
+
+    // Verify the position account
+    const position = await program.account.stakePosition.fetch(positionPda);
+    expect(position.vault.toString()).to.equal(vaultPda.toString());
+    expect(position.index.toNumber()).to.equal(vaultAccountBefore.nextPositionIndex.toNumber());
+    expect(position.amount.toNumber()).to.equal(stakeAmount.toNumber());
+    expect(position.stakeTimestamp.toNumber()).to.be.greaterThan(0);
 
     console.log("   📊 After Stake:");
     console.log("      Staked amount:", vaultAccountAfter.stakedAmount.toNumber());
-    console.log("      Is staked:", vaultAccountAfter.isStaked);
-    console.log("      Stake timestamp:", vaultAccountAfter.stakeTimestamp.toNumber());
+    console.log("      Position:", position.index.toNumber());
+    console.log("      Stake timestamp:", position.stakeTimestamp.toNumber());
     console.log("   ✅ Staking successful!");
   });
 
@@ -186,23 +405,31 @@ describe("vault-staking", () => {
     // First, stake some funds
     const stakeAmount = new BN(500_000_000); // 0.5 SOL
 
-    // Unstake the previous stake first
+    // Unstake the position from TEST 3 first
     await program.methods
-      .unstake()
+      .requestUnstake(new BN(1_000_000_000), false)
       .accounts({
         vault: vaultPda,
+        stakePosition: findPositionPda(0),
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        payer: user.publicKey,
       })
       .rpc();
 
     // Now stake new amount
+    const positionPda = await nextPositionPda();
     await program.methods
-      .stake(stakeAmount)
+      .stake(stakeAmount, NO_LOCK)
       .accounts({
         vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
 
@@ -217,11 +444,15 @@ describe("vault-staking", () => {
 
     // Unstake
     const tx = await program.methods
-      .unstake()
+      .requestUnstake(stakeAmount, false)
       .accounts({
         vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        payer: user.publicKey,
       })
       .rpc();
 
@@ -231,12 +462,15 @@ describe("vault-staking", () => {
     const vaultAccountAfter = await program.account.vault.fetch(vaultPda);
     const totalAfter = vaultAccountAfter.totalDeposited.toNumber();
 
-    expect(vaultAccountAfter.isStaked).to.equal(false);
     expect(vaultAccountAfter.stakedAmount.toNumber()).to.equal(0);
 
     // Total should not increase (no rewards)
     expect(totalAfter).to.equal(totalBefore);
 
+    // The position account is closed and its rent refunded
+    const positionInfo = await provider.connection.getAccountInfo(positionPda);
+    expect(positionInfo).to.equal(null);
+
     console.log("   ❌ No rewards earned (duration < 60 seconds)");
     console.log("   ✅ Principal returned:", stakeAmount.toNumber());
   });
@@ -251,15 +485,20 @@ describe("vault-staking", () => {
     const stakeAmount = new BN(1_000_000_000);
 
     // Stake funds
+    const positionPda = await nextPositionPda();
     await program.methods
-      .stake(stakeAmount)
+      .stake(stakeAmount, NO_LOCK)
       .accounts({
         vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
 
+    const stakeStartedAt = Date.now();
     const vaultAccountBefore = await program.account.vault.fetch(vaultPda);
     const totalBefore = vaultAccountBefore.totalDeposited.toNumber();
 
@@ -270,18 +509,21 @@ describe("vault-staking", () => {
     // Wait 61 seconds to meet minimum duration
     await sleep(61000);
 
-    // Calculate expected reward (10% of staked amount)
-    const expectedReward = calculateReward(stakeAmount.toNumber());
-
-    console.log("   💰 Expected reward:", expectedReward, "lamports");
+    // Snapshot real balances to check the reward lamports actually move
+    const poolBalanceBefore = await provider.connection.getBalance(rewardPoolPda);
+    const vaultBalanceBefore = await provider.connection.getBalance(vaultPda);
 
     // Unstake
     const tx = await program.methods
-      .unstake()
+      .requestUnstake(stakeAmount, false)
       .accounts({
         vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        payer: user.publicKey,
       })
       .rpc();
 
@@ -291,12 +533,24 @@ describe("vault-staking", () => {
     const vaultAccountAfter = await program.account.vault.fetch(vaultPda);
     const totalAfter = vaultAccountAfter.totalDeposited.toNumber();
 
-    expect(vaultAccountAfter.isStaked).to.equal(false);
     expect(vaultAccountAfter.stakedAmount.toNumber()).to.equal(0);
 
-    // Total should increase by reward amount
+    // Total should increase by the reward for the on-chain stake duration.
+    // The exact duration depends on validator timestamps, so we check a range:
+    // at least the cliff (61s waited), at most the wall-clock time plus slack.
     const actualReward = totalAfter - totalBefore;
-    expect(actualReward).to.equal(expectedReward);
+    const elapsedSeconds = Math.ceil((Date.now() - stakeStartedAt) / 1000);
+    expect(actualReward).to.be.at.least(calculateReward(stakeAmount.toNumber(), MIN_STAKE_DURATION));
+    expect(actualReward).to.be.at.most(calculateReward(stakeAmount.toNumber(), elapsedSeconds + 5));
+
+    // Reward lamports really moved from the pool into the vault
+    const poolBalanceAfter = await provider.connection.getBalance(rewardPoolPda);
+    const vaultBalanceAfter = await provider.connection.getBalance(vaultPda);
+    expect(poolBalanceBefore - poolBalanceAfter).to.equal(actualReward);
+    expect(vaultBalanceAfter - vaultBalanceBefore).to.equal(actualReward);
+
+    const rewardPool = await program.account.rewardPool.fetch(rewardPoolPda);
+    expect(rewardPool.totalPaid.toNumber()).to.equal(actualReward);
 
     console.log("   ✅ Rewards received:", actualReward, "lamports");
     console.log("   📊 New total balance:", totalAfter, "lamports");
@@ -308,6 +562,9 @@ describe("vault-staking", () => {
   it("Withdraws unstaked funds from vault", async () => {
     console.log("\n🧪 TEST 6: Withdraw Funds");
 
+    // Unstaked principal only becomes withdrawable once unbonding completes
+    await completePendingUnstake();
+
     const vaultAccount = await program.account.vault.fetch(vaultPda);
     const availableBalance = vaultAccount.totalDeposited.toNumber();
 
@@ -324,9 +581,8 @@ describe("vault-staking", () => {
       .withdraw(withdrawAmount)
       .accounts({
         vault: vaultPda,
+        config: configPda,
         user: user.publicKey,
-        owner: user.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
 
@@ -368,118 +624,3018 @@ describe("vault-staking", () => {
     }
   });
 
-  it("Fails to stake when already staked", async () => {
-    console.log("\n🧪 TEST: Error - Double Stake");
+  it("Opens independent positions when staking twice", async () => {
+    console.log("\n🧪 TEST: Multiple Stake Positions");
+
+    const firstAmount = new BN(100_000_000);
+    const secondAmount = new BN(200_000_000);
 
-    // First stake
+    const firstPositionPda = await nextPositionPda();
     await program.methods
-      .stake(new BN(100_000_000))
+      .stake(firstAmount, NO_LOCK)
       .accounts({
         vault: vaultPda,
+        stakePosition: firstPositionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
 
-    // Try to stake again (should fail)
-    try {
-      await program.methods
-        .stake(new BN(100_000_000))
-        .accounts({
-          vault: vaultPda,
-          user: user.publicKey,
-          owner: user.publicKey,
-        })
-        .rpc();
+    // Adding to the stake opens a second position instead of failing
+    const secondPositionPda = await nextPositionPda();
+    await program.methods
+      .stake(secondAmount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: secondPositionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
 
-      expect.fail("Should have thrown AlreadyStaked error");
-    } catch (error: any) {
-      expect(error.error.errorCode.code).to.equal("AlreadyStaked");
-      console.log("   ✅ Correctly rejected: AlreadyStaked");
-    }
+    const firstPosition = await program.account.stakePosition.fetch(firstPositionPda);
+    const secondPosition = await program.account.stakePosition.fetch(secondPositionPda);
+    expect(secondPosition.index.toNumber()).to.equal(firstPosition.index.toNumber() + 1);
+    expect(firstPosition.amount.toNumber()).to.equal(firstAmount.toNumber());
+    expect(secondPosition.amount.toNumber()).to.equal(secondAmount.toNumber());
+
+    let vaultAccount = await program.account.vault.fetch(vaultPda);
+    expect(vaultAccount.stakedAmount.toNumber()).to.equal(
+      firstAmount.toNumber() + secondAmount.toNumber()
+    );
+    console.log("   ✅ Two positions open:", firstPosition.index.toNumber(), "and", secondPosition.index.toNumber());
+
+    // Unstaking one position leaves the other (and its timer) untouched
+    await program.methods
+      .requestUnstake(firstAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: firstPositionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+
+    vaultAccount = await program.account.vault.fetch(vaultPda);
+    expect(vaultAccount.stakedAmount.toNumber()).to.equal(secondAmount.toNumber());
+    const secondPositionAfter = await program.account.stakePosition.fetch(secondPositionPda);
+    expect(secondPositionAfter.stakeTimestamp.toNumber()).to.equal(
+      secondPosition.stakeTimestamp.toNumber()
+    );
+    console.log("   ✅ Unstaked the first position, second still staked");
+
+    // Cleanup: unstake the second position
+    await program.methods
+      .requestUnstake(secondAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: secondPositionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Partially unstakes a position and keeps its timer", async () => {
+    console.log("\n🧪 TEST: Partial Unstake");
+
+    const stakeAmount = new BN(400_000_000);
+    const unstakeAmount = new BN(100_000_000);
+
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const positionBefore = await program.account.stakePosition.fetch(positionPda);
+
+    // Release only part of the position
+    await program.methods
+      .requestUnstake(unstakeAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+
+    // The remainder stays staked with its original timestamp
+    const positionAfter = await program.account.stakePosition.fetch(positionPda);
+    expect(positionAfter.amount.toNumber()).to.equal(
+      stakeAmount.toNumber() - unstakeAmount.toNumber()
+    );
+    expect(positionAfter.stakeTimestamp.toNumber()).to.equal(
+      positionBefore.stakeTimestamp.toNumber()
+    );
+
+    const vaultAccount = await program.account.vault.fetch(vaultPda);
+    expect(vaultAccount.stakedAmount.toNumber()).to.equal(positionAfter.amount.toNumber());
+    console.log("   ✅ Unstaked", unstakeAmount.toNumber(), "- remaining:", positionAfter.amount.toNumber());
 
-    // Cleanup: unstake
+    // Unstaking the rest closes the position
     await program.methods
-      .unstake()
+      .requestUnstake(positionAfter.amount, false)
       .accounts({
         vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
         user: user.publicKey,
-        owner: user.publicKey,
+        payer: user.publicKey,
       })
       .rpc();
+
+    const positionInfo = await provider.connection.getAccountInfo(positionPda);
+    expect(positionInfo).to.equal(null);
+    console.log("   ✅ Remainder unstaked, position closed");
   });
 
-  it("Fails to unstake when not staked", async () => {
-    console.log("\n🧪 TEST: Error - Unstake Without Staking");
+  it("Fails to unstake more than the position holds", async () => {
+    console.log("\n🧪 TEST: Error - Unstake Exceeding Position");
+
+    const stakeAmount = new BN(100_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
 
     try {
       await program.methods
-        .unstake()
+        .requestUnstake(stakeAmount.addn(1), false)
         .accounts({
           vault: vaultPda,
+          stakePosition: positionPda,
+          rewardPool: rewardPoolPda,
+          config: configPda,
+          stakePool: stakePoolPda,
           user: user.publicKey,
-          owner: user.publicKey,
+          payer: user.publicKey,
         })
         .rpc();
 
-      expect.fail("Should have thrown NotStaked error");
+      expect.fail("Should have thrown InsufficientStakedBalance error");
     } catch (error: any) {
-      expect(error.error.errorCode.code).to.equal("NotStaked");
-      console.log("   ✅ Correctly rejected: NotStaked");
+      expect(error.error.errorCode.code).to.equal("InsufficientStakedBalance");
+      console.log("   ✅ Correctly rejected: InsufficientStakedBalance");
     }
+
+    // Cleanup: unstake the whole position
+    await program.methods
+      .requestUnstake(stakeAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
   });
 
-  it("Fails to withdraw more than available balance", async () => {
-    console.log("\n🧪 TEST: Error - Withdraw Exceeding Balance");
+  it("Claims rewards without unstaking", async () => {
+    console.log("\n🧪 TEST: Claim Rewards");
 
-    const vaultAccount = await program.account.vault.fetch(vaultPda);
-    const availableBalance = vaultAccount.totalDeposited.toNumber();
+    // Drop the cliff so the test doesn't need to wait 60 seconds
+    await program.methods
+      .updateConfig(new BN(0), new BN(REWARD_RATE_BASIS_POINTS), new BN(COMPOUND_TIP_BASIS_POINTS), new BN(TEST_UNBONDING_PERIOD), new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS), new BN(TEST_WITHDRAWAL_DELAY))
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
 
-    // Try to withdraw more than available
-    const excessiveAmount = new BN(availableBalance + 1_000_000_000);
+    const stakeAmount = new BN(500_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    console.log("   ⏰ Waiting 3 seconds for rewards to accrue...");
+    await sleep(3000);
+
+    const positionBefore = await program.account.stakePosition.fetch(positionPda);
+    const vaultBefore = await program.account.vault.fetch(vaultPda);
+
+    await program.methods
+      .claimRewards()
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        user: user.publicKey,
+      })
+      .rpc();
+
+    const positionAfter = await program.account.stakePosition.fetch(positionPda);
+    const vaultAfter = await program.account.vault.fetch(vaultPda);
+    const claimed = vaultAfter.totalDeposited.toNumber() - vaultBefore.totalDeposited.toNumber();
+
+    // Rewards land in the available balance, principal stays staked
+    expect(claimed).to.be.greaterThan(0);
+    expect(vaultAfter.stakedAmount.toNumber()).to.equal(vaultBefore.stakedAmount.toNumber());
+    expect(positionAfter.amount.toNumber()).to.equal(stakeAmount.toNumber());
+
+    // The accrual checkpoint moves, the stake timestamp does not
+    expect(positionAfter.stakeTimestamp.toNumber()).to.equal(positionBefore.stakeTimestamp.toNumber());
+    expect(positionAfter.lastClaimTimestamp.toNumber()).to.be.greaterThan(
+      positionBefore.lastClaimTimestamp.toNumber()
+    );
+    console.log("   ✅ Claimed", claimed, "lamports, position still staked");
+
+    // Cleanup: unstake and restore the default cliff
+    await program.methods
+      .requestUnstake(stakeAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .updateConfig(
+        new BN(MIN_STAKE_DURATION),
+        new BN(REWARD_RATE_BASIS_POINTS),
+        new BN(COMPOUND_TIP_BASIS_POINTS),
+        new BN(TEST_UNBONDING_PERIOD),
+        new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+        new BN(TEST_WITHDRAWAL_DELAY)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Fails to claim rewards before the minimum duration", async () => {
+    console.log("\n🧪 TEST: Error - Claim Before Cliff");
+
+    const stakeAmount = new BN(100_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
 
     try {
       await program.methods
-        .withdraw(excessiveAmount)
+        .claimRewards()
         .accounts({
           vault: vaultPda,
+          stakePosition: positionPda,
+          rewardPool: rewardPoolPda,
+          config: configPda,
           user: user.publicKey,
-          owner: user.publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
 
-      expect.fail("Should have thrown InsufficientFunds error");
+      expect.fail("Should have thrown MinimumStakeDurationNotMet error");
     } catch (error: any) {
-      expect(error.error.errorCode.code).to.equal("InsufficientFunds");
-      console.log("   ✅ Correctly rejected: InsufficientFunds");
+      expect(error.error.errorCode.code).to.equal("MinimumStakeDurationNotMet");
+      console.log("   ✅ Correctly rejected: MinimumStakeDurationNotMet");
     }
+
+    // Cleanup: unstake the position
+    await program.methods
+      .requestUnstake(stakeAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
   });
 
-  it("Fails to stake more than available balance", async () => {
-    console.log("\n🧪 TEST: Error - Stake Exceeding Balance");
+  it("Fails to unstake a locked position without accepting the penalty", async () => {
+    console.log("\n🧪 TEST: Error - Unstake Inside Lock Period");
 
-    const vaultAccount = await program.account.vault.fetch(vaultPda);
-    const availableBalance = vaultAccount.totalDeposited.toNumber();
+    const stakeAmount = new BN(100_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, SEVEN_DAY_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
 
-    // Try to stake more than available
-    const excessiveAmount = new BN(availableBalance + 1_000_000_000);
+    // The lock ends exactly one tier duration after staking
+    const position = await program.account.stakePosition.fetch(positionPda);
+    expect(position.lockTier).to.deep.equal(SEVEN_DAY_LOCK);
+    expect(position.lockEnd.toNumber()).to.equal(
+      position.stakeTimestamp.toNumber() + SEVEN_DAYS_SECONDS
+    );
 
     try {
       await program.methods
-        .stake(excessiveAmount)
+        .requestUnstake(stakeAmount, false)
         .accounts({
           vault: vaultPda,
+          stakePosition: positionPda,
+          rewardPool: rewardPoolPda,
+          config: configPda,
+          stakePool: stakePoolPda,
           user: user.publicKey,
-          owner: user.publicKey,
+          payer: user.publicKey,
         })
         .rpc();
 
-      expect.fail("Should have thrown InsufficientUnstakedBalance error");
+      expect.fail("Should have thrown LockPeriodActive error");
     } catch (error: any) {
-      expect(error.error.errorCode.code).to.equal("InsufficientUnstakedBalance");
-      console.log("   ✅ Correctly rejected: InsufficientUnstakedBalance");
+      expect(error.error.errorCode.code).to.equal("LockPeriodActive");
+      console.log("   ✅ Correctly rejected: LockPeriodActive");
     }
+
+    // Cleanup: leave early with the penalty
+    await program.methods
+      .requestUnstake(stakeAmount, true)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Unstakes a locked position early by paying the penalty", async () => {
+    console.log("\n🧪 TEST: Early Exit Penalty");
+
+    const stakeAmount = new BN(200_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, SEVEN_DAY_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const vaultBefore = await program.account.vault.fetch(vaultPda);
+    const poolBefore = await program.account.rewardPool.fetch(rewardPoolPda);
+    const poolBalanceBefore = await provider.connection.getBalance(rewardPoolPda);
+
+    await program.methods
+      .requestUnstake(stakeAmount, true)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+
+    const penalty = Math.floor(
+      (stakeAmount.toNumber() * EARLY_EXIT_PENALTY_BASIS_POINTS) / BASIS_POINTS_DIVISOR
+    );
+
+    // Principal is released minus the penalty, with no rewards
+    const vaultAfter = await program.account.vault.fetch(vaultPda);
+    expect(vaultAfter.stakedAmount.toNumber()).to.equal(
+      vaultBefore.stakedAmount.toNumber() - stakeAmount.toNumber()
+    );
+    expect(vaultAfter.totalDeposited.toNumber()).to.equal(
+      vaultBefore.totalDeposited.toNumber() - penalty
+    );
+
+    // The penalty lamports land in the reward pool
+    const poolAfter = await program.account.rewardPool.fetch(rewardPoolPda);
+    const poolBalanceAfter = await provider.connection.getBalance(rewardPoolPda);
+    expect(poolBalanceAfter - poolBalanceBefore).to.equal(penalty);
+    expect(poolAfter.totalPenalties.toNumber() - poolBefore.totalPenalties.toNumber()).to.equal(penalty);
+
+    console.log("   ✅ Early exit cost", penalty, "lamports, sent to the reward pool");
+  });
+
+  it("Refuses to claim a locked position's rewards before an early exit", async () => {
+    console.log("\n🧪 TEST: Error - Claim Then Exit Inside Lock Period");
+
+    const stakeAmount = new BN(100_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, SEVEN_DAY_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // Step 1: the boosted rewards can't be pulled out while the lock runs
+    try {
+      await program.methods
+        .claimRewards()
+        .accounts({
+          vault: vaultPda,
+          stakePosition: positionPda,
+          rewardPool: rewardPoolPda,
+          config: configPda,
+          user: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown LockPeriodActive error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("LockPeriodActive");
+      console.log("   ✅ Claim rejected: LockPeriodActive");
+    }
+
+    // Step 2: leaving early pays no rewards at all, only the penalty
+    const sig = await program.methods
+      .requestUnstake(stakeAmount, true)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const unstaked = (await eventsFrom(sig)).find((e) => e.name === "unstaked");
+    expect(unstaked.data.rewardEarned.toNumber()).to.equal(0);
+    expect(unstaked.data.penalty.toNumber()).to.equal(
+      Math.floor((stakeAmount.toNumber() * EARLY_EXIT_PENALTY_BASIS_POINTS) / BASIS_POINTS_DIVISOR)
+    );
+
+    console.log("   ✅ Early exit kept no boosted rewards");
+  });
+
+  it("Charges the early unstake penalty before the minimum duration", async () => {
+    console.log("\n🧪 TEST: Early Unstake Penalty (before the cliff)");
+
+    // Turn the optional penalty on: 5% of principal before the 60s cliff
+    const PENALTY_BASIS_POINTS = 500;
+    await program.methods
+      .updateConfig(
+        new BN(MIN_STAKE_DURATION),
+        new BN(REWARD_RATE_BASIS_POINTS),
+        new BN(COMPOUND_TIP_BASIS_POINTS),
+        new BN(TEST_UNBONDING_PERIOD),
+        new BN(PENALTY_BASIS_POINTS),
+        new BN(TEST_WITHDRAWAL_DELAY)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+
+    const stakeAmount = new BN(100_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const vaultBefore = await program.account.vault.fetch(vaultPda);
+    const poolBefore = await program.account.rewardPool.fetch(rewardPoolPda);
+    const poolBalanceBefore = await provider.connection.getBalance(rewardPoolPda);
+
+    // No lock, so no flag needed - but the cliff has not been met yet
+    await program.methods
+      .requestUnstake(stakeAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+
+    const penalty = Math.floor(
+      (stakeAmount.toNumber() * PENALTY_BASIS_POINTS) / BASIS_POINTS_DIVISOR
+    );
+
+    // Only the principal minus the penalty is pending
+    const vaultAfter = await program.account.vault.fetch(vaultPda);
+    expect(vaultAfter.totalDeposited.toNumber()).to.equal(
+      vaultBefore.totalDeposited.toNumber() - penalty
+    );
+    expect(vaultAfter.pendingUnstake.toNumber()).to.equal(
+      vaultBefore.pendingUnstake.toNumber() + stakeAmount.toNumber() - penalty
+    );
+
+    // The penalty lamports land in the reward pool
+    const poolAfter = await program.account.rewardPool.fetch(rewardPoolPda);
+    const poolBalanceAfter = await provider.connection.getBalance(rewardPoolPda);
+    expect(poolBalanceAfter - poolBalanceBefore).to.equal(penalty);
+    expect(poolAfter.totalPenalties.toNumber() - poolBefore.totalPenalties.toNumber()).to.equal(penalty);
+
+    console.log("   ✅ Early unstake cost", penalty, "lamports, sent to the reward pool");
+
+    // Restore the default (no penalty) for the remaining tests
+    await program.methods
+      .updateConfig(
+        new BN(MIN_STAKE_DURATION),
+        new BN(REWARD_RATE_BASIS_POINTS),
+        new BN(COMPOUND_TIP_BASIS_POINTS),
+        new BN(TEST_UNBONDING_PERIOD),
+        new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+        new BN(TEST_WITHDRAWAL_DELAY)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Holds unstaked funds until the unbonding cooldown ends", async () => {
+    console.log("\n🧪 TEST: Unbonding Cooldown");
+
+    const UNBONDING_SECONDS = 3;
+    await completePendingUnstake();
+
+    await program.methods
+      .updateConfig(
+        new BN(MIN_STAKE_DURATION),
+        new BN(REWARD_RATE_BASIS_POINTS),
+        new BN(COMPOUND_TIP_BASIS_POINTS),
+        new BN(UNBONDING_SECONDS),
+        new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+        new BN(TEST_WITHDRAWAL_DELAY)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+
+    const stakeAmount = new BN(100_000_000);
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(stakeAmount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const vaultBefore = await program.account.vault.fetch(vaultPda);
+
+    await program.methods
+      .requestUnstake(stakeAmount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc();
+
+    // Principal left the stake but is not available yet
+    const vaultPending = await program.account.vault.fetch(vaultPda);
+    expect(vaultPending.stakedAmount.toNumber()).to.equal(
+      vaultBefore.stakedAmount.toNumber() - stakeAmount.toNumber()
+    );
+    expect(vaultPending.pendingUnstake.toNumber()).to.equal(stakeAmount.toNumber());
+    expect(vaultPending.unbondingEnd.toNumber()).to.be.greaterThan(0);
+    console.log("   📊 Pending:", vaultPending.pendingUnstake.toNumber(), "until", vaultPending.unbondingEnd.toNumber());
+
+    // Withdraw can't touch pending funds
+    const available =
+      vaultPending.totalDeposited.toNumber() -
+      vaultPending.stakedAmount.toNumber() -
+      vaultPending.pendingUnstake.toNumber();
+    try {
+      await program.methods
+        .withdraw(new BN(available + 1))
+        .accounts({
+          vault: vaultPda,
+          config: configPda,
+          user: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown InsufficientFunds error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("InsufficientFunds");
+      console.log("   ✅ Withdraw of pending funds rejected: InsufficientFunds");
+    }
+
+    // Completing before the cooldown ends fails
+    try {
+      await program.methods
+        .completeUnstake()
+        .accounts({
+          vault: vaultPda,
+          user: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown UnbondingPeriodActive error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("UnbondingPeriodActive");
+      console.log("   ✅ Early completion rejected: UnbondingPeriodActive");
+    }
+
+    console.log("   ⏰ Waiting", UNBONDING_SECONDS + 2, "seconds for the cooldown...");
+    await sleep((UNBONDING_SECONDS + 2) * 1000);
+
+    await program.methods
+      .completeUnstake()
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+      })
+      .rpc();
+
+    const vaultAfter = await program.account.vault.fetch(vaultPda);
+    expect(vaultAfter.pendingUnstake.toNumber()).to.equal(0);
+    console.log("   ✅ Unbonding complete, funds available again");
+
+    // Restore the test config
+    await program.methods
+      .updateConfig(
+        new BN(MIN_STAKE_DURATION),
+        new BN(REWARD_RATE_BASIS_POINTS),
+        new BN(COMPOUND_TIP_BASIS_POINTS),
+        new BN(TEST_UNBONDING_PERIOD),
+        new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+        new BN(TEST_WITHDRAWAL_DELAY)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Fails to complete an unstake when nothing is pending", async () => {
+    console.log("\n🧪 TEST: Error - Complete Without Pending Funds");
+
+    await completePendingUnstake();
+
+    try {
+      await program.methods
+        .completeUnstake()
+        .accounts({
+          vault: vaultPda,
+          user: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown NoPendingUnstake error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("NoPendingUnstake");
+      console.log("   ✅ Correctly rejected: NoPendingUnstake");
+    }
+  });
+
+  it("Fails to unstake a position that is already closed", async () => {
+    console.log("\n🧪 TEST: Error - Unstake Closed Position");
+
+    // Position 0 was unstaked (and closed) in TEST 4
+    try {
+      await program.methods
+        .requestUnstake(new BN(1_000), false)
+        .accounts({
+          vault: vaultPda,
+          stakePosition: findPositionPda(0),
+          rewardPool: rewardPoolPda,
+          config: configPda,
+          stakePool: stakePoolPda,
+          user: user.publicKey,
+          payer: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have failed on a closed position");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("AccountNotInitialized");
+      console.log("   ✅ Correctly rejected: position already closed");
+    }
+  });
+
+  it("Fails to withdraw more than available balance", async () => {
+    console.log("\n🧪 TEST: Error - Withdraw Exceeding Balance");
+
+    const vaultAccount = await program.account.vault.fetch(vaultPda);
+    const availableBalance = vaultAccount.totalDeposited.toNumber();
+
+    // Try to withdraw more than available
+    const excessiveAmount = new BN(availableBalance + 1_000_000_000);
+
+    try {
+      await program.methods
+        .withdraw(excessiveAmount)
+        .accounts({
+          vault: vaultPda,
+          config: configPda,
+          user: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown InsufficientFunds error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("InsufficientFunds");
+      console.log("   ✅ Correctly rejected: InsufficientFunds");
+    }
+  });
+
+  it("Fails to stake more than available balance", async () => {
+    console.log("\n🧪 TEST: Error - Stake Exceeding Balance");
+
+    const vaultAccount = await program.account.vault.fetch(vaultPda);
+    const availableBalance = vaultAccount.totalDeposited.toNumber();
+
+    // Try to stake more than available
+    const excessiveAmount = new BN(availableBalance + 1_000_000_000);
+
+    try {
+      await program.methods
+        .stake(excessiveAmount, NO_LOCK)
+        .accounts({
+          vault: vaultPda,
+          stakePosition: await nextPositionPda(),
+          config: configPda,
+          stakePool: stakePoolPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown InsufficientUnstakedBalance error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("InsufficientUnstakedBalance");
+      console.log("   ✅ Correctly rejected: InsufficientUnstakedBalance");
+    }
+  });
+
+  // ==============================================
+  // CONFIG TESTS
+  // ==============================================
+
+  it("Lets the admin update the staking config", async () => {
+    console.log("\n🧪 TEST: Config - Admin update");
+
+    await program.methods
+      .updateConfig(new BN(120), new BN(500), new BN(100), new BN(3600), new BN(250), new BN(45))
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+
+    let config = await program.account.stakingConfig.fetch(configPda);
+    expect(config.minStakeDuration.toNumber()).to.equal(120);
+    expect(config.rewardRateBasisPoints.toNumber()).to.equal(500);
+    expect(config.compoundTipBasisPoints.toNumber()).to.equal(100);
+    expect(config.unbondingPeriod.toNumber()).to.equal(3600);
+    expect(config.earlyUnstakePenaltyBasisPoints.toNumber()).to.equal(250);
+    expect(config.withdrawalDelay.toNumber()).to.equal(45);
+    console.log("   ✅ Config updated: 120s cliff, 500 bps APR, 100 bps tip, 1h unbonding, 250 bps penalty, 45s withdrawal delay");
+
+    // Restore the defaults for the remaining tests
+    await program.methods
+      .updateConfig(
+        new BN(MIN_STAKE_DURATION),
+        new BN(REWARD_RATE_BASIS_POINTS),
+        new BN(COMPOUND_TIP_BASIS_POINTS),
+        new BN(TEST_UNBONDING_PERIOD),
+        new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+        new BN(TEST_WITHDRAWAL_DELAY)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+
+    config = await program.account.stakingConfig.fetch(configPda);
+    expect(config.minStakeDuration.toNumber()).to.equal(MIN_STAKE_DURATION);
+    expect(config.rewardRateBasisPoints.toNumber()).to.equal(REWARD_RATE_BASIS_POINTS);
+    expect(config.compoundTipBasisPoints.toNumber()).to.equal(COMPOUND_TIP_BASIS_POINTS);
+    expect(config.unbondingPeriod.toNumber()).to.equal(TEST_UNBONDING_PERIOD);
+    expect(config.earlyUnstakePenaltyBasisPoints.toNumber()).to.equal(EARLY_UNSTAKE_PENALTY_BASIS_POINTS);
+    expect(config.withdrawalDelay.toNumber()).to.equal(TEST_WITHDRAWAL_DELAY);
+  });
+
+  it("Fails to set a negative minimum stake duration", async () => {
+    console.log("\n🧪 TEST: Error - Negative min duration");
+
+    try {
+      await program.methods
+        .updateConfig(new BN(-1), new BN(REWARD_RATE_BASIS_POINTS), new BN(COMPOUND_TIP_BASIS_POINTS), new BN(TEST_UNBONDING_PERIOD), new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS), new BN(TEST_WITHDRAWAL_DELAY))
+        .accounts({
+          config: configPda,
+          admin: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown InvalidConfig error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      console.log("   ✅ Correctly rejected: InvalidConfig");
+    }
+  });
+
+  it("Fails to set a compound tip above 100%", async () => {
+    console.log("\n🧪 TEST: Error - Compound tip > 10000 bps");
+
+    try {
+      await program.methods
+        .updateConfig(
+          new BN(MIN_STAKE_DURATION),
+          new BN(REWARD_RATE_BASIS_POINTS),
+          new BN(10_001),
+          new BN(TEST_UNBONDING_PERIOD),
+          new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+          new BN(TEST_WITHDRAWAL_DELAY)
+        )
+        .accounts({
+          config: configPda,
+          admin: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown InvalidConfig error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      console.log("   ✅ Correctly rejected: InvalidConfig");
+    }
+  });
+
+  it("Fails to set an early unstake penalty above 100%", async () => {
+    console.log("\n🧪 TEST: Error - Early unstake penalty > 10000 bps");
+
+    try {
+      await program.methods
+        .updateConfig(
+          new BN(MIN_STAKE_DURATION),
+          new BN(REWARD_RATE_BASIS_POINTS),
+          new BN(COMPOUND_TIP_BASIS_POINTS),
+          new BN(TEST_UNBONDING_PERIOD),
+          new BN(10_001),
+          new BN(TEST_WITHDRAWAL_DELAY)
+        )
+        .accounts({
+          config: configPda,
+          admin: user.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown InvalidConfig error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      console.log("   ✅ Correctly rejected: InvalidConfig");
+    }
+  });
+
+  // ==============================================
+  // WITHDRAWAL QUEUE TESTS
+  // ==============================================
+
+  it("Lets the admin update the lock tier multipliers and early exit penalty", async () => {
+    console.log("\n🧪 TEST: Config - Lock tiers");
+
+    const sig = await program.methods
+      .updateLockTiers(new BN(10_500), new BN(12_000), new BN(20_000), new BN(500))
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    let config = await program.account.stakingConfig.fetch(configPda);
+    expect(config.lockMultiplierOneDayBasisPoints.toNumber()).to.equal(10_500);
+    expect(config.lockMultiplierSevenDaysBasisPoints.toNumber()).to.equal(12_000);
+    expect(config.lockMultiplierThirtyDaysBasisPoints.toNumber()).to.equal(20_000);
+    expect(config.earlyExitPenaltyBasisPoints.toNumber()).to.equal(500);
+
+    const updated = (await eventsFrom(sig)).find((e) => e.name === "configUpdated");
+    expect(updated.data.lockMultiplierThirtyDaysBasisPoints.toNumber()).to.equal(20_000);
+    expect(updated.data.earlyExitPenaltyBasisPoints.toNumber()).to.equal(500);
+    console.log("   ✅ Lock tiers updated: 1.05x / 1.2x / 2x, 5% early exit penalty");
+
+    // Restore the defaults for the remaining tests
+    await program.methods
+      .updateLockTiers(
+        new BN(LOCK_MULTIPLIERS[0]),
+        new BN(LOCK_MULTIPLIERS[1]),
+        new BN(LOCK_MULTIPLIERS[2]),
+        new BN(EARLY_EXIT_PENALTY_BASIS_POINTS)
+      )
+      .accounts({
+        config: configPda,
+        admin: user.publicKey,
+      })
+      .rpc();
+
+    config = await program.account.stakingConfig.fetch(configPda);
+    expect(config.lockMultiplierSevenDaysBasisPoints.toNumber()).to.equal(LOCK_MULTIPLIERS[1]);
+    expect(config.earlyExitPenaltyBasisPoints.toNumber()).to.equal(EARLY_EXIT_PENALTY_BASIS_POINTS);
+  });
+
+  it("Fails to set lock multipliers below 1x, out of order, or a penalty above 100%", async () => {
+    console.log("\n🧪 TEST: Error - Invalid lock tiers");
+
+    const invalid = [
+      [9_000, 12_500, 15_000, EARLY_EXIT_PENALTY_BASIS_POINTS], // OneDay below 1x
+      [11_000, 16_000, 15_000, EARLY_EXIT_PENALTY_BASIS_POINTS], // SevenDays above ThirtyDays
+      [11_000, 12_500, 15_000, 10_001], // penalty above 100%
+    ];
+    for (const [oneDay, sevenDays, thirtyDays, penalty] of invalid) {
+      try {
+        await program.methods
+          .updateLockTiers(new BN(oneDay), new BN(sevenDays), new BN(thirtyDays), new BN(penalty))
+          .accounts({
+            config: configPda,
+            admin: user.publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown InvalidConfig error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      }
+    }
+    console.log("   ✅ Correctly rejected all three: InvalidConfig");
+  });
+
+  it("Pays what an underfunded reward pool has and reports the shortfall", async () => {
+    console.log("\n🧪 TEST: Reward Pool - Partial payout");
+
+    const setRewardTerms = (minDuration: number, rate: BN) =>
+      program.methods
+        .updateConfig(
+          new BN(minDuration),
+          rate,
+          new BN(COMPOUND_TIP_BASIS_POINTS),
+          new BN(TEST_UNBONDING_PERIOD),
+          new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+          new BN(TEST_WITHDRAWAL_DELAY)
+        )
+        .accounts({
+          config: configPda,
+          admin: user.publicKey,
+        })
+        .rpc();
+
+    // Lamports the pool can pay while staying rent-exempt
+    const rewardPoolAvailable = async () => {
+      const info = await provider.connection.getAccountInfo(rewardPoolPda);
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(info.data.length);
+      return info.lamports - rent;
+    };
+
+    const amount = new BN(100_000_000);
+    await program.methods
+      .deposit(amount)
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(amount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // No cliff and an absurd APR, so the reward is far more than the pool holds
+    await setRewardTerms(0, new BN("1000000000000000"));
+    await sleep(2000);
+
+    const availableBefore = await rewardPoolAvailable();
+    const sig = await program.methods
+      .requestUnstake(amount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+    await setRewardTerms(MIN_STAKE_DURATION, new BN(REWARD_RATE_BASIS_POINTS));
+
+    // The unstake went through and the pool paid everything it had...
+    const events = await eventsFrom(sig);
+    const paid = events.find((e) => e.name === "rewardPaid" && e.data.source.unstake !== undefined);
+    expect(paid.data.amount.toNumber()).to.equal(availableBefore);
+    expect(await rewardPoolAvailable()).to.equal(0);
+
+    // ...and the event shows what was earned and what was lost
+    const unstaked = events.find((e) => e.name === "unstaked");
+    const earned = unstaked.data.rewardEarned;
+    const shortfall = unstaked.data.rewardShortfall;
+    expect(earned.gt(new BN(availableBefore))).to.equal(true);
+    expect(shortfall.toString()).to.equal(earned.sub(new BN(availableBefore)).toString());
+    console.log("   ✅ Earned", earned.toString(), "paid", availableBefore, "shortfall", shortfall.toString());
+
+    // Refill the pool for the tests below
+    await program.methods
+      .fundRewardPool(new BN(availableBefore))
+      .accounts({
+        rewardPool: rewardPoolPda,
+        admin: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await completePendingUnstake();
+  });
+
+  it("Time-locks withdrawals through the withdrawal queue", async () => {
+    console.log("\n🧪 TEST: Withdrawal Queue - queue, cancel, execute");
+
+    const WITHDRAWAL_DELAY = 3;
+    const setWithdrawalDelay = (delay: number) =>
+      program.methods
+        .updateConfig(
+          new BN(MIN_STAKE_DURATION),
+          new BN(REWARD_RATE_BASIS_POINTS),
+          new BN(COMPOUND_TIP_BASIS_POINTS),
+          new BN(TEST_UNBONDING_PERIOD),
+          new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+          new BN(delay)
+        )
+        .accounts({
+          config: configPda,
+          admin: user.publicKey,
+        })
+        .rpc();
+
+    await completePendingUnstake();
+    await setWithdrawalDelay(WITHDRAWAL_DELAY);
+
+    const amount = new BN(1_000_000);
+    const queueWithdraw = () =>
+      program.methods
+        .queueWithdraw(amount)
+        .accounts({ vault: vaultPda, config: configPda, user: user.publicKey })
+        .rpc();
+    const executeWithdraw = () =>
+      program.methods
+        .executeWithdraw()
+        .accounts({ vault: vaultPda, user: user.publicKey })
+        .rpc();
+
+    // Instant withdraw is off while the delay is on
+    try {
+      await program.methods
+        .withdraw(amount)
+        .accounts({ vault: vaultPda, config: configPda, user: user.publicKey })
+        .rpc();
+      expect.fail("Should have thrown WithdrawalDelayActive error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("WithdrawalDelayActive");
+      console.log("   ✅ Correctly rejected instant withdraw: WithdrawalDelayActive");
+    }
+
+    // Queued funds are reserved: no longer part of the available balance
+    const available = (v: any) =>
+      v.totalDeposited.sub(v.stakedAmount).sub(v.pendingUnstake).sub(v.queuedWithdrawal).toNumber();
+    const before = await program.account.vault.fetch(vaultPda);
+    await queueWithdraw();
+    let vaultAccount = await program.account.vault.fetch(vaultPda);
+    expect(vaultAccount.queuedWithdrawal.toNumber()).to.equal(amount.toNumber());
+    expect(available(vaultAccount)).to.equal(available(before) - amount.toNumber());
+    console.log("   ✅ Queued", amount.toNumber(), "lamports until", vaultAccount.withdrawalUnlockTime.toNumber());
+
+    // Too early to execute
+    try {
+      await executeWithdraw();
+      expect.fail("Should have thrown WithdrawalLocked error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("WithdrawalLocked");
+      console.log("   ✅ Correctly rejected: WithdrawalLocked");
+    }
+
+    // Cancelling releases the reservation right away
+    await program.methods
+      .cancelWithdraw()
+      .accounts({ vault: vaultPda, user: user.publicKey })
+      .rpc();
+    vaultAccount = await program.account.vault.fetch(vaultPda);
+    expect(vaultAccount.queuedWithdrawal.toNumber()).to.equal(0);
+    expect(available(vaultAccount)).to.equal(available(before));
+    console.log("   ✅ Cancelled; funds available again");
+
+    // Queue again and wait out the delay
+    await queueWithdraw();
+    await sleep((WITHDRAWAL_DELAY + 1) * 1000);
+    await executeWithdraw();
+
+    vaultAccount = await program.account.vault.fetch(vaultPda);
+    expect(vaultAccount.queuedWithdrawal.toNumber()).to.equal(0);
+    expect(vaultAccount.totalDeposited.toNumber()).to.equal(
+      before.totalDeposited.toNumber() - amount.toNumber()
+    );
+    console.log("   ✅ Executed after", WITHDRAWAL_DELAY, "seconds");
+
+    await setWithdrawalDelay(TEST_WITHDRAWAL_DELAY);
+  });
+
+  // ==============================================
+  // CLOSE VAULT TESTS
+  // ==============================================
+
+  it("Closes a vault only once nothing is staked or unbonding", async () => {
+    console.log("\n🧪 TEST: Close Vault");
+
+    // A throwaway owner so the shared vault stays open for other tests
+    const owner = anchor.web3.Keypair.generate();
+    await airdrop(owner.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+    const [ownerVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), owner.publicKey.toBuffer()],
+      program.programId
+    );
+    const [ownerPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_position"), ownerVaultPda.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initialize()
+      .accounts({
+        vault: ownerVaultPda,
+        user: owner.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+    await program.methods
+      .deposit(new BN(200_000_000))
+      .accounts({
+        vault: ownerVaultPda,
+        user: owner.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+    await program.methods
+      .stake(new BN(100_000_000), NO_LOCK)
+      .accounts({
+        vault: ownerVaultPda,
+        stakePosition: ownerPositionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: owner.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+
+    const closeVault = () =>
+      program.methods
+        .closeVault()
+        .accounts({
+          vault: ownerVaultPda,
+          config: configPda,
+          owner: owner.publicKey,
+        })
+        .signers([owner])
+        .rpc();
+
+    // Staked funds block the close
+    try {
+      await closeVault();
+      expect.fail("Should have thrown FundsStaked error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("FundsStaked");
+      console.log("   ✅ Correctly rejected: FundsStaked");
+    }
+
+    await program.methods
+      .requestUnstake(new BN(100_000_000), false)
+      .accounts({
+        vault: ownerVaultPda,
+        stakePosition: ownerPositionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: owner.publicKey,
+        payer: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+
+    // So do funds still waiting in the unbonding bucket
+    try {
+      await closeVault();
+      expect.fail("Should have thrown UnstakePending error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("UnstakePending");
+      console.log("   ✅ Correctly rejected: UnstakePending");
+    }
+
+    await program.methods
+      .completeUnstake()
+      .accounts({
+        vault: ownerVaultPda,
+        user: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+
+    // An open Delegation blocks the close too: its PDA would outlive the
+    // vault and come back to life if the same owner re-initialized it
+    const bot = anchor.web3.Keypair.generate();
+    const [delegationPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("delegation"), ownerVaultPda.toBuffer(), bot.publicKey.toBuffer()],
+      program.programId
+    );
+    const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+    await program.methods
+      .grantDelegation(bot.publicKey, new BN(1_000_000), new BN(3600), new BN(now + 3600))
+      .accounts({
+        vault: ownerVaultPda,
+        config: configPda,
+        delegation: delegationPda,
+        owner: owner.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+    try {
+      await closeVault();
+      expect.fail("Should have thrown OpenDelegations error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("OpenDelegations");
+      console.log("   ✅ Correctly rejected: OpenDelegations");
+    }
+    await program.methods
+      .revokeDelegation()
+      .accounts({ vault: ownerVaultPda, delegation: delegationPda, owner: owner.publicKey, grantedBy: owner.publicKey })
+      .signers([owner])
+      .rpc();
+    expect((await program.account.vault.fetch(ownerVaultPda)).openDelegations.toNumber()).to.equal(0);
+
+    const vaultLamports = await provider.connection.getBalance(ownerVaultPda);
+    const ownerBefore = await provider.connection.getBalance(owner.publicKey);
+
+    await closeVault();
+
+    // Account gone, balance + rent back with the owner (minus the tx fee)
+    expect(await program.account.vault.fetchNullable(ownerVaultPda)).to.be.null;
+    const ownerAfter = await provider.connection.getBalance(owner.publicKey);
+    expect(ownerAfter).to.be.above(ownerBefore + vaultLamports - 10_000);
+
+    console.log("   ✅ Vault closed,", vaultLamports, "lamports returned to the owner");
+  });
+
+  // ==============================================
+  // EVENT TESTS
+  // ==============================================
+
+  it("Emits structured events for deposit, stake and unstake", async () => {
+    console.log("\n🧪 TEST: Events");
+
+    const amount = new BN(50_000_000);
+
+    // CONCEPT: Events Instead of Log Strings
+    // Each handler emits a typed event; we decode it from the confirmed logs
+    const depositSig = await program.methods
+      .deposit(amount)
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const deposited = (await eventsFrom(depositSig)).find((e) => e.name === "deposited");
+    const vaultAfterDeposit = await program.account.vault.fetch(vaultPda);
+    expect(deposited.data.owner.toString()).to.equal(user.publicKey.toString());
+    expect(deposited.data.vault.toString()).to.equal(vaultPda.toString());
+    expect(deposited.data.funder.toString()).to.equal(user.publicKey.toString());
+    expect(deposited.data.amount.toNumber()).to.equal(amount.toNumber());
+    expect(deposited.data.totalDeposited.toNumber()).to.equal(vaultAfterDeposit.totalDeposited.toNumber());
+
+    const positionPda = await nextPositionPda();
+    const stakeSig = await program.methods
+      .stake(amount, NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const staked = (await eventsFrom(stakeSig)).find((e) => e.name === "staked");
+    const position = await program.account.stakePosition.fetch(positionPda);
+    expect(staked.data.positionIndex.toNumber()).to.equal(position.index.toNumber());
+    expect(staked.data.amount.toNumber()).to.equal(amount.toNumber());
+    expect(staked.data.lockTier).to.deep.equal(NO_LOCK);
+
+    const unstakeSig = await program.methods
+      .requestUnstake(amount, false)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        rewardPool: rewardPoolPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        payer: user.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const unstaked = (await eventsFrom(unstakeSig)).find((e) => e.name === "unstaked");
+    const vaultAfterUnstake = await program.account.vault.fetch(vaultPda);
+    expect(unstaked.data.amount.toNumber()).to.equal(amount.toNumber());
+    expect(unstaked.data.penalty.toNumber()).to.equal(0);
+    expect(unstaked.data.positionClosed).to.equal(true);
+    expect(unstaked.data.pendingUnstake.toNumber()).to.equal(vaultAfterUnstake.pendingUnstake.toNumber());
+
+    console.log("   ✅ Deposited, Staked and Unstaked events decoded");
+
+    await completePendingUnstake();
+  });
+
+  it("Emits events when the admin initializes the config and pools", async () => {
+    console.log("\n🧪 TEST: Events - Admin initializers");
+
+    // Config starts from the compile-time defaults (updateConfig changed them afterwards)
+    const configInitialized = (await eventsFrom(initConfigSig)).find((e) => e.name === "configInitialized");
+    expect(configInitialized.data.admin.toString()).to.equal(user.publicKey.toString());
+    expect(configInitialized.data.minStakeDuration.toNumber()).to.equal(60);
+    expect(configInitialized.data.rewardRateBasisPoints.toNumber()).to.equal(1000);
+    expect(configInitialized.data.compoundTipBasisPoints.toNumber()).to.equal(50);
+    expect(configInitialized.data.unbondingPeriod.toNumber()).to.equal(30);
+    expect(configInitialized.data.earlyUnstakePenaltyBasisPoints.toNumber()).to.equal(0);
+    expect(configInitialized.data.withdrawalDelay.toNumber()).to.equal(0);
+    expect(configInitialized.data.lockMultiplierOneDayBasisPoints.toNumber()).to.equal(LOCK_MULTIPLIERS[0]);
+    expect(configInitialized.data.lockMultiplierSevenDaysBasisPoints.toNumber()).to.equal(LOCK_MULTIPLIERS[1]);
+    expect(configInitialized.data.lockMultiplierThirtyDaysBasisPoints.toNumber()).to.equal(LOCK_MULTIPLIERS[2]);
+    expect(configInitialized.data.earlyExitPenaltyBasisPoints.toNumber()).to.equal(EARLY_EXIT_PENALTY_BASIS_POINTS);
+
+    const rewardPoolInitialized = (await eventsFrom(initRewardPoolSig)).find(
+      (e) => e.name === "rewardPoolInitialized"
+    );
+    expect(rewardPoolInitialized.data.admin.toString()).to.equal(user.publicKey.toString());
+
+    const stakePoolInitialized = (await eventsFrom(initStakePoolSig)).find(
+      (e) => e.name === "stakePoolInitialized"
+    );
+    const stakePool = await program.account.stakePool.fetch(stakePoolPda);
+    expect(stakePoolInitialized.data.admin.toString()).to.equal(user.publicKey.toString());
+    expect(stakePoolInitialized.data.emissionRate.toNumber()).to.equal(STAKE_POOL_EMISSION_RATE.toNumber());
+    expect(stakePoolInitialized.data.timestamp.toNumber()).to.be.at.most(stakePool.lastUpdateTimestamp.toNumber());
+
+    console.log("   ✅ ConfigInitialized, RewardPoolInitialized and StakePoolInitialized events decoded");
+  });
+
+  it("Lets anyone deposit into someone else's vault", async () => {
+    console.log("\n🧪 TEST: Deposit For");
+
+    const payer = anchor.web3.Keypair.generate();
+    await airdrop(payer.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    const amount = new BN(100_000_000);
+
+    // The owner argument must match the vault
+    try {
+      await program.methods
+        .depositFor(payer.publicKey, amount)
+        .accounts({
+          vault: vaultPda,
+          payer: payer.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+      expect.fail("Should have thrown VaultOwnerMismatch error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("VaultOwnerMismatch");
+      console.log("   ✅ Correctly rejected: VaultOwnerMismatch");
+    }
+
+    const vaultBefore = await program.account.vault.fetch(vaultPda);
+    const sig = await program.methods
+      .depositFor(user.publicKey, amount)
+      .accounts({
+        vault: vaultPda,
+        payer: payer.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: "confirmed" });
+
+    const vaultAfter = await program.account.vault.fetch(vaultPda);
+    expect(vaultAfter.totalDeposited.toNumber()).to.equal(
+      vaultBefore.totalDeposited.toNumber() + amount.toNumber()
+    );
+
+    // The funder is recorded in the event, the owner is credited
+    const deposited = (await eventsFrom(sig)).find((e) => e.name === "deposited");
+    expect(deposited.data.owner.toString()).to.equal(user.publicKey.toString());
+    expect(deposited.data.funder.toString()).to.equal(payer.publicKey.toString());
+    console.log("   ✅ Payer funded the vault:", amount.toNumber(), "lamports");
+  });
+
+  it("Transfers available balance between vaults in one instruction", async () => {
+    console.log("\n🧪 TEST: Transfer Between Vaults");
+
+    const other = anchor.web3.Keypair.generate();
+    await airdrop(other.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    const [otherVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), other.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .initialize()
+      .accounts({
+        vault: otherVaultPda,
+        user: other.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([other])
+      .rpc();
+    const amount = new BN(50_000_000);
+
+    // Only the source vault's owner or delegate can move its funds
+    try {
+      await program.methods
+        .transferBetweenVaults(amount)
+        .accounts({ fromVault: vaultPda, toVault: otherVaultPda, config: configPda, user: other.publicKey })
+        .signers([other])
+        .rpc();
+      expect.fail("Should have thrown Unauthorized error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("Unauthorized");
+      console.log("   ✅ Correctly rejected: Unauthorized");
+    }
+
+    try {
+      await program.methods
+        .transferBetweenVaults(amount)
+        .accounts({ fromVault: vaultPda, toVault: vaultPda, config: configPda, user: user.publicKey })
+        .rpc();
+      expect.fail("Should have thrown SameVault error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("SameVault");
+      console.log("   ✅ Correctly rejected: SameVault");
+    }
+
+    const fromBefore = await program.account.vault.fetch(vaultPda);
+    const sig = await program.methods
+      .transferBetweenVaults(amount)
+      .accounts({ fromVault: vaultPda, toVault: otherVaultPda, config: configPda, user: user.publicKey })
+      .rpc({ commitment: "confirmed" });
+
+    const fromAfter = await program.account.vault.fetch(vaultPda);
+    const toAfter = await program.account.vault.fetch(otherVaultPda);
+    expect(fromAfter.totalDeposited.toNumber()).to.equal(
+      fromBefore.totalDeposited.toNumber() - amount.toNumber()
+    );
+    expect(toAfter.totalDeposited.toNumber()).to.equal(amount.toNumber());
+
+    // One event names both sides of the move
+    const transfer = (await eventsFrom(sig)).find((e) => e.name === "internalTransfer");
+    expect(transfer.data.fromVault.toString()).to.equal(vaultPda.toString());
+    expect(transfer.data.toVault.toString()).to.equal(otherVaultPda.toString());
+    expect(transfer.data.toOwner.toString()).to.equal(other.publicKey.toString());
+    expect(transfer.data.amount.toNumber()).to.equal(amount.toNumber());
+    console.log("   ✅ Moved", amount.toNumber(), "lamports vault-to-vault");
+  });
+
+  // ==============================================
+  // STAKE POOL TESTS
+  // ==============================================
+
+  it("Splits stake pool emissions pro rata between vaults", async () => {
+    console.log("\n🧪 TEST: Stake Pool - Pro Rata Emissions");
+
+    // A second staker with their own vault
+    const other = anchor.web3.Keypair.generate();
+    await airdrop(other.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+    const [otherVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), other.publicKey.toBuffer()],
+      program.programId
+    );
+    const [otherPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_position"), otherVaultPda.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initialize()
+      .accounts({
+        vault: otherVaultPda,
+        user: other.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([other])
+      .rpc();
+    await program.methods
+      .deposit(new BN(500_000_000))
+      .accounts({
+        vault: otherVaultPda,
+        user: other.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([other])
+      .rpc();
+    await program.methods
+      .stake(new BN(300_000_000), NO_LOCK)
+      .accounts({
+        vault: otherVaultPda,
+        stakePosition: otherPositionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: other.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([other])
+      .rpc();
+
+    // Make sure our vault has a stake too
+    await program.methods
+      .deposit(new BN(100_000_000))
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    const positionPda = await nextPositionPda();
+    await program.methods
+      .stake(new BN(100_000_000), NO_LOCK)
+      .accounts({
+        vault: vaultPda,
+        stakePosition: positionPda,
+        config: configPda,
+        stakePool: stakePoolPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // Fund a budget that runs out within ~2 seconds at the emission rate
+    const budget = STAKE_POOL_EMISSION_RATE.muln(2);
+    await program.methods
+      .fundStakePool(budget)
+      .accounts({
+        stakePool: stakePoolPda,
+        admin: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    await sleep(4000);
+
+    const ourStake = (await program.account.vault.fetch(vaultPda)).stakedAmount.toNumber();
+    const otherStake = (await program.account.vault.fetch(otherVaultPda)).stakedAmount.toNumber();
+
+    const ourBefore = (await program.account.vault.fetch(vaultPda)).totalDeposited.toNumber();
+    await program.methods
+      .harvestPoolRewards()
+      .accounts({ vault: vaultPda, stakePool: stakePoolPda, user: user.publicKey })
+      .rpc();
+    const ourHarvest = (await program.account.vault.fetch(vaultPda)).totalDeposited.toNumber() - ourBefore;
+
+    const otherBefore = (await program.account.vault.fetch(otherVaultPda)).totalDeposited.toNumber();
+    await program.methods
+      .harvestPoolRewards()
+      .accounts({ vault: otherVaultPda, stakePool: stakePoolPda, user: other.publicKey })
+      .signers([other])
+      .rpc();
+    const otherHarvest = (await program.account.vault.fetch(otherVaultPda)).totalDeposited.toNumber() - otherBefore;
+
+    // The whole budget was emitted and split by stake (minus rounding dust)
+    const stakePool = await program.account.stakePool.fetch(stakePoolPda);
+    expect(stakePool.undistributed.toNumber()).to.equal(0);
+    expect(ourHarvest + otherHarvest).to.be.at.most(budget.toNumber());
+    expect(ourHarvest + otherHarvest).to.be.at.least(budget.toNumber() - 2);
+
+    const expectedOurs = Math.floor((budget.toNumber() * ourStake) / (ourStake + otherStake));
+    expect(Math.abs(ourHarvest - expectedOurs)).to.be.at.most(1);
+
+    console.log("   ✅ Budget", budget.toString(), "split:", ourHarvest, "/", otherHarvest);
+
+    // Budget exhausted: nothing left to harvest
+    try {
+      await program.methods
+        .harvestPoolRewards()
+        .accounts({ vault: vaultPda, stakePool: stakePoolPda, user: user.publicKey })
+        .rpc();
+      expect.fail("Should have thrown NoRewardsToClaim error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("NoRewardsToClaim");
+      console.log("   ✅ Nothing left to harvest once the budget is emitted");
+    }
+  });
+
+  it("Requires the stake pool once it has been initialized", async () => {
+    console.log("\n🧪 TEST: Stake Pool - Required once active");
+
+    // The pool is optional so staking works before it exists; once it does,
+    // skipping it would leave total_staked and reward_debt out of sync
+    const config = await program.account.stakingConfig.fetch(configPda);
+    expect(config.stakePoolActive).to.equal(true);
+
+    await program.methods
+      .deposit(new BN(10_000_000))
+      .accounts({
+        vault: vaultPda,
+        user: user.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .stake(new BN(10_000_000), NO_LOCK)
+        .accounts({
+          vault: vaultPda,
+          stakePosition: await nextPositionPda(),
+          config: configPda,
+          stakePool: null,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Should have thrown StakePoolRequired error");
+    } catch (error: any) {
+      expect(error.error.errorCode.code).to.equal("StakePoolRequired");
+      console.log("   ✅ Correctly rejected: StakePoolRequired");
+    }
+  });
+
+  it("Lets the admin change the stake pool emission rate", async () => {
+    console.log("\n🧪 TEST: Stake Pool - Update emission rate");
+
+    const before = await program.account.stakePool.fetch(stakePoolPda);
+    const newRate = STAKE_POOL_EMISSION_RATE.muln(2);
+
+    const sig = await program.methods
+      .updateStakePoolRate(newRate)
+      .accounts({ stakePool: stakePoolPda, admin: user.publicKey })
+      .rpc({ commitment: "confirmed" });
+
+    // Emissions up to now were settled at the old rate before the change
+    const after = await program.account.stakePool.fetch(stakePoolPda);
+    expect(after.emissionRate.toNumber()).to.equal(newRate.toNumber());
+    expect(after.lastUpdateTimestamp.toNumber()).to.be.at.least(before.lastUpdateTimestamp.toNumber());
+
+    const updated = (await eventsFrom(sig)).find((e) => e.name === "stakePoolRateUpdated");
+    expect(updated.data.admin.toString()).to.equal(user.publicKey.toString());
+    expect(updated.data.previousRate.toNumber()).to.equal(before.emissionRate.toNumber());
+    expect(updated.data.emissionRate.toNumber()).to.equal(newRate.toNumber());
+    console.log("   ✅ Emission rate changed from", before.emissionRate.toString(), "to", newRate.toString());
+
+    // Put the original rate back for the tests below
+    await program.methods
+      .updateStakePoolRate(STAKE_POOL_EMISSION_RATE)
+      .accounts({ stakePool: stakePoolPda, admin: user.publicKey })
+      .rpc();
+  });
+
+  // ==============================================
+  // AUTHORITY TESTS
+  // ==============================================
+  // An attacker passes the victim's vault but signs with their own key.
+  // Every fund-moving instruction must reject them.
+
+  describe("authority", () => {
+    const attacker = anchor.web3.Keypair.generate();
+    const delegate = anchor.web3.Keypair.generate();
+
+    // An open position owned by the victim's vault
+    let victimPositionPda: anchor.web3.PublicKey;
+
+    before(async () => {
+      await airdrop(attacker.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await airdrop(delegate.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await completePendingUnstake();
+
+      victimPositionPda = await nextPositionPda();
+      await program.methods
+        .stake(new BN(1_000_000), NO_LOCK)
+        .accounts({
+          vault: vaultPda,
+          stakePosition: victimPositionPda,
+          config: configPda,
+          stakePool: stakePoolPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Rejects an attacker withdrawing from someone else's vault", async () => {
+      console.log("\n🧪 TEST: Authority - Attacker withdraw");
+
+      try {
+        await program.methods
+          .withdraw(new BN(1_000))
+          .accounts({
+            vault: vaultPda,
+            config: configPda,
+            user: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Rejects an attacker staking someone else's funds", async () => {
+      console.log("\n🧪 TEST: Authority - Attacker stake");
+
+      try {
+        await program.methods
+          .stake(new BN(1_000), NO_LOCK)
+          .accounts({
+            vault: vaultPda,
+            stakePosition: await nextPositionPda(),
+            config: configPda,
+            stakePool: stakePoolPda,
+            user: attacker.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Rejects an attacker unstaking someone else's funds", async () => {
+      console.log("\n🧪 TEST: Authority - Attacker unstake");
+
+      try {
+        await program.methods
+          .requestUnstake(new BN(1_000), false)
+          .accounts({
+            vault: vaultPda,
+            stakePosition: victimPositionPda,
+            rewardPool: rewardPoolPda,
+            config: configPda,
+            stakePool: stakePoolPda,
+            user: attacker.publicKey,
+            payer: user.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Rejects an attacker completing someone else's unstake", async () => {
+      console.log("\n🧪 TEST: Authority - Attacker complete_unstake");
+
+      try {
+        await program.methods
+          .completeUnstake()
+          .accounts({
+            vault: vaultPda,
+            user: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Rejects an attacker appointing themselves as delegate", async () => {
+      console.log("\n🧪 TEST: Authority - Attacker set_delegate");
+
+      try {
+        await program.methods
+          .setDelegate(attacker.publicKey)
+          .accounts({
+            vault: vaultPda,
+            owner: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have rejected the attacker");
+      } catch (error: any) {
+        // Owner-only: has_one = owner rejects any other signer
+        expect(error.error.errorCode.code).to.equal("ConstraintHasOne");
+        console.log("   ✅ Correctly rejected: ConstraintHasOne");
+      }
+    });
+
+    it("Rejects an attacker enabling auto-compound on someone else's vault", async () => {
+      console.log("\n🧪 TEST: Authority - Attacker set_auto_compound");
+
+      try {
+        await program.methods
+          .setAutoCompound(true)
+          .accounts({
+            vault: vaultPda,
+            owner: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have rejected the attacker");
+      } catch (error: any) {
+        // Owner-only: has_one = owner rejects any other signer
+        expect(error.error.errorCode.code).to.equal("ConstraintHasOne");
+        console.log("   ✅ Correctly rejected: ConstraintHasOne");
+      }
+    });
+
+    it("Rejects an attacker closing someone else's vault", async () => {
+      console.log("\n🧪 TEST: Authority - Attacker close_vault");
+
+      try {
+        await program.methods
+          .closeVault()
+          .accounts({
+            vault: vaultPda,
+            config: configPda,
+            owner: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have rejected the attacker");
+      } catch (error: any) {
+        // Owner-only: has_one = owner rejects any other signer
+        expect(error.error.errorCode.code).to.equal("ConstraintHasOne");
+        console.log("   ✅ Correctly rejected: ConstraintHasOne");
+      }
+    });
+
+    it("Rejects a non-admin funding the reward pool", async () => {
+      console.log("\n🧪 TEST: Authority - Non-admin fund_reward_pool");
+
+      try {
+        await program.methods
+          .fundRewardPool(new BN(1_000))
+          .accounts({
+            rewardPool: rewardPoolPda,
+            admin: attacker.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Rejects a non-admin changing the stake pool rate", async () => {
+      console.log("\n🧪 TEST: Authority - Non-admin update_stake_pool_rate");
+
+      try {
+        await program.methods
+          .updateStakePoolRate(new BN(1_000_000_000))
+          .accounts({ stakePool: stakePoolPda, admin: attacker.publicKey })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Rejects a non-admin updating the lock tiers", async () => {
+      console.log("\n🧪 TEST: Authority - Non-admin update_lock_tiers");
+
+      try {
+        await program.methods
+          .updateLockTiers(new BN(50_000), new BN(50_000), new BN(50_000), new BN(0))
+          .accounts({
+            config: configPda,
+            admin: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Rejects a non-admin updating the staking config", async () => {
+      console.log("\n🧪 TEST: Authority - Non-admin update_config");
+
+      try {
+        await program.methods
+          .updateConfig(new BN(0), new BN(10_000), new BN(0), new BN(0), new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS), new BN(TEST_WITHDRAWAL_DELAY))
+          .accounts({
+            config: configPda,
+            admin: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Lets an authorized delegate withdraw until revoked", async () => {
+      console.log("\n🧪 TEST: Authority - Delegate withdraw + revoke");
+
+      await program.methods
+        .setDelegate(delegate.publicKey)
+        .accounts({
+          vault: vaultPda,
+          owner: user.publicKey,
+        })
+        .rpc();
+
+      const withdrawAmount = new BN(1_000_000);
+      const delegateBalanceBefore = await provider.connection.getBalance(delegate.publicKey);
+
+      await program.methods
+        .withdraw(withdrawAmount)
+        .accounts({
+          vault: vaultPda,
+          config: configPda,
+          user: delegate.publicKey,
+        })
+        .signers([delegate])
+        .rpc();
+
+      const delegateBalanceAfter = await provider.connection.getBalance(delegate.publicKey);
+      // Delegate receives the lamports (minus its own tx fee)
+      expect(delegateBalanceAfter - delegateBalanceBefore).to.be.greaterThan(
+        withdrawAmount.toNumber() - 10_000
+      );
+      console.log("   ✅ Delegate withdrew", withdrawAmount.toNumber(), "lamports");
+
+      // Owner revokes the delegate
+      await program.methods
+        .setDelegate(null)
+        .accounts({
+          vault: vaultPda,
+          owner: user.publicKey,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .withdraw(withdrawAmount)
+          .accounts({
+            vault: vaultPda,
+            config: configPda,
+            user: delegate.publicKey,
+          })
+          .signers([delegate])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Revoked delegate correctly rejected: Unauthorized");
+      }
+    });
+
+    it("Refunds a closed position's rent to whoever paid for it", async () => {
+      console.log("\n🧪 TEST: Authority - Position rent goes back to its payer");
+
+      await program.methods
+        .setDelegate(delegate.publicKey)
+        .accounts({ vault: vaultPda, owner: user.publicKey })
+        .rpc();
+
+      // The owner opens (and pays the rent for) a position
+      const amount = new BN(10_000_000);
+      await program.methods
+        .deposit(amount)
+        .accounts({
+          vault: vaultPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      const positionPda = await nextPositionPda();
+      await program.methods
+        .stake(amount, NO_LOCK)
+        .accounts({
+          vault: vaultPda,
+          stakePosition: positionPda,
+          config: configPda,
+          stakePool: stakePoolPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      const position = await program.account.stakePosition.fetch(positionPda);
+      expect(position.payer.toString()).to.equal(user.publicKey.toString());
+
+      const delegateUnstake = (payer: anchor.web3.PublicKey) =>
+        program.methods
+          .requestUnstake(amount, false)
+          .accounts({
+            vault: vaultPda,
+            stakePosition: positionPda,
+            rewardPool: rewardPoolPda,
+            config: configPda,
+            stakePool: stakePoolPda,
+            user: delegate.publicKey,
+            payer,
+          })
+          .signers([delegate])
+          .rpc();
+
+      // The delegate can't redirect the rent to itself
+      try {
+        await delegateUnstake(delegate.publicKey);
+        expect.fail("Should have thrown ConstraintHasOne error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ConstraintHasOne");
+        console.log("   ✅ Correctly rejected a different rent receiver: ConstraintHasOne");
+      }
+
+      // The delegate closes the position; the rent goes back to the owner
+      const rent = await provider.connection.getBalance(positionPda);
+      const delegateBalanceBefore = await provider.connection.getBalance(delegate.publicKey);
+      const ownerBalanceBefore = await provider.connection.getBalance(user.publicKey);
+      await delegateUnstake(user.publicKey);
+      const ownerBalanceAfter = await provider.connection.getBalance(user.publicKey);
+
+      expect(await program.account.stakePosition.fetchNullable(positionPda)).to.be.null;
+      // The owner's wallet also pays the transaction fee
+      expect(ownerBalanceAfter - ownerBalanceBefore).to.be.greaterThan(rent - 10_000);
+      expect(await provider.connection.getBalance(delegate.publicKey)).to.equal(delegateBalanceBefore);
+      console.log("   ✅ Position closed by the delegate,", rent, "lamports of rent refunded to the owner");
+
+      await completePendingUnstake();
+      await program.methods
+        .setDelegate(null)
+        .accounts({ vault: vaultPda, owner: user.publicKey })
+        .rpc();
+    });
+
+    it("Caps a limited delegate's withdrawals per period", async () => {
+      console.log("\n🧪 TEST: Authority - Delegation with spending limit");
+
+      const bot = anchor.web3.Keypair.generate();
+      const [delegationPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("delegation"), vaultPda.toBuffer(), bot.publicKey.toBuffer()],
+        program.programId
+      );
+      const limit = 1_000_000;
+      const periodSeconds = 10;
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+
+      await program.methods
+        .grantDelegation(bot.publicKey, new BN(limit), new BN(periodSeconds), new BN(now + 3600))
+        .accounts({
+          vault: vaultPda,
+          config: configPda,
+          delegation: delegationPda,
+          owner: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const botWithdraw = (amount: number) =>
+        program.methods
+          .withdraw(new BN(amount))
+          .accounts({ vault: vaultPda, config: configPda, delegation: delegationPda, user: bot.publicKey })
+          .signers([bot])
+          .rpc();
+
+      // The full allowance is available once the grant is active
+      await botWithdraw(limit);
+      let delegation = await program.account.delegation.fetch(delegationPda);
+      expect(delegation.allowance.toNumber()).to.equal(0);
+      console.log("   ✅ Bot withdrew its full", limit, "lamport allowance");
+
+      // Nothing has refilled yet
+      try {
+        await botWithdraw(600_000);
+        expect.fail("Should have thrown DelegationLimitExceeded error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("DelegationLimitExceeded");
+        console.log("   ✅ Correctly rejected: DelegationLimitExceeded");
+      }
+
+      // Without its Delegation the bot has no authority at all
+      try {
+        await program.methods
+          .withdraw(new BN(1_000))
+          .accounts({ vault: vaultPda, config: configPda, delegation: null, user: bot.publicKey })
+          .signers([bot])
+          .rpc();
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Correctly rejected without the Delegation: Unauthorized");
+      }
+
+      // Half a period later only about half the limit has refilled, so a
+      // spend right after a fixed-window boundary could not take the full limit again
+      await sleep((periodSeconds / 2) * 1000);
+      try {
+        await botWithdraw(limit);
+        expect.fail("Should have thrown DelegationLimitExceeded error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("DelegationLimitExceeded");
+        console.log("   ✅ Spend across the period boundary capped by the refill");
+      }
+      await botWithdraw(300_000);
+      delegation = await program.account.delegation.fetch(delegationPda);
+      expect(delegation.allowance.toNumber()).to.be.lessThan(limit - 300_000);
+      console.log("   ✅ Bot withdrew 300000 of the partial refill");
+
+      // A full period later the allowance is back at the limit (and no higher)
+      await sleep((periodSeconds + 1) * 1000);
+      await botWithdraw(limit);
+      delegation = await program.account.delegation.fetch(delegationPda);
+      expect(delegation.allowance.toNumber()).to.equal(0);
+      console.log("   ✅ Allowance refilled to the limit after", periodSeconds, "seconds");
+
+      // The owner revokes the grant and gets its rent back
+      await program.methods
+        .revokeDelegation()
+        .accounts({ vault: vaultPda, delegation: delegationPda, owner: user.publicKey, grantedBy: user.publicKey })
+        .rpc();
+      expect(await program.account.delegation.fetchNullable(delegationPda)).to.be.null;
+      console.log("   ✅ Delegation revoked");
+    });
+
+    it("Rejects withdrawals on an expired delegation", async () => {
+      console.log("\n🧪 TEST: Authority - Expired delegation");
+
+      const bot = anchor.web3.Keypair.generate();
+      const [delegationPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("delegation"), vaultPda.toBuffer(), bot.publicKey.toBuffer()],
+        program.programId
+      );
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+
+      await program.methods
+        .grantDelegation(bot.publicKey, new BN(1_000_000), new BN(3600), new BN(now + 2))
+        .accounts({
+          vault: vaultPda,
+          config: configPda,
+          delegation: delegationPda,
+          owner: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await sleep(4000);
+
+      try {
+        await program.methods
+          .withdraw(new BN(1_000))
+          .accounts({ vault: vaultPda, config: configPda, delegation: delegationPda, user: bot.publicKey })
+          .signers([bot])
+          .rpc();
+        expect.fail("Should have thrown DelegationExpired error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("DelegationExpired");
+        console.log("   ✅ Correctly rejected: DelegationExpired");
+      }
+    });
+
+    it("Transfers vault ownership in two steps without moving funds", async () => {
+      console.log("\n🧪 TEST: Authority - propose_owner + accept_owner");
+
+      // A throwaway vault so the shared one keeps its owner
+      const oldOwner = anchor.web3.Keypair.generate();
+      const newOwner = anchor.web3.Keypair.generate();
+      await airdrop(oldOwner.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await airdrop(newOwner.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      // Seeds use the creator, so this address survives the transfer
+      const [transferVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), oldOwner.publicKey.toBuffer()],
+        program.programId
+      );
+      const depositAmount = new BN(100_000_000);
+
+      await program.methods
+        .initialize()
+        .accounts({
+          vault: transferVaultPda,
+          user: oldOwner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([oldOwner])
+        .rpc();
+      await program.methods
+        .deposit(depositAmount)
+        .accounts({
+          vault: transferVaultPda,
+          user: oldOwner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([oldOwner])
+        .rpc();
+      await program.methods
+        .setDelegate(delegate.publicKey)
+        .accounts({ vault: transferVaultPda, owner: oldOwner.publicKey })
+        .signers([oldOwner])
+        .rpc();
+
+      // Step 1: the owner proposes; nothing changes hands yet
+      await program.methods
+        .proposeOwner(newOwner.publicKey)
+        .accounts({ vault: transferVaultPda, config: configPda, owner: oldOwner.publicKey })
+        .signers([oldOwner])
+        .rpc();
+      let vaultAccount = await program.account.vault.fetch(transferVaultPda);
+      expect(vaultAccount.pendingOwner.toBase58()).to.equal(newOwner.publicKey.toBase58());
+      expect(vaultAccount.owner.toBase58()).to.equal(oldOwner.publicKey.toBase58());
+
+      // Only the proposed key can accept
+      try {
+        await program.methods
+          .acceptOwner()
+          .accounts({ vault: transferVaultPda, newOwner: attacker.publicKey })
+          .signers([attacker])
+          .rpc();
+        expect.fail("Should have thrown NotPendingOwner error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotPendingOwner");
+        console.log("   ✅ Correctly rejected: NotPendingOwner");
+      }
+
+      // Step 2: the new owner accepts
+      await program.methods
+        .acceptOwner()
+        .accounts({ vault: transferVaultPda, newOwner: newOwner.publicKey })
+        .signers([newOwner])
+        .rpc();
+      vaultAccount = await program.account.vault.fetch(transferVaultPda);
+      expect(vaultAccount.owner.toBase58()).to.equal(newOwner.publicKey.toBase58());
+      expect(vaultAccount.creator.toBase58()).to.equal(oldOwner.publicKey.toBase58());
+      expect(vaultAccount.pendingOwner).to.be.null;
+      expect(vaultAccount.delegate).to.be.null; // the old owner's delegate is dropped
+      expect(vaultAccount.totalDeposited.toNumber()).to.equal(depositAmount.toNumber());
+      console.log("   ✅ Ownership transferred, balance kept at the same address");
+
+      // The old owner lost access...
+      try {
+        await program.methods
+          .withdraw(depositAmount)
+          .accounts({ vault: transferVaultPda, config: configPda, user: oldOwner.publicKey })
+          .signers([oldOwner])
+          .rpc();
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Old owner correctly rejected: Unauthorized");
+      }
+
+      // ...and the new owner can withdraw
+      await program.methods
+        .withdraw(depositAmount)
+        .accounts({ vault: transferVaultPda, config: configPda, user: newOwner.publicKey })
+        .signers([newOwner])
+        .rpc();
+      vaultAccount = await program.account.vault.fetch(transferVaultPda);
+      expect(vaultAccount.totalDeposited.toNumber()).to.equal(0);
+      console.log("   ✅ New owner withdrew", depositAmount.toNumber(), "lamports");
+    });
+
+    it("Retires delegations after an ownership round trip and refunds their rent to the granter", async () => {
+      console.log("\n🧪 TEST: Authority - Ownership epoch retires old grants");
+
+      // A throwaway vault that goes A -> B -> A
+      const ownerA = anchor.web3.Keypair.generate();
+      const ownerB = anchor.web3.Keypair.generate();
+      const bot = anchor.web3.Keypair.generate();
+      await airdrop(ownerA.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await airdrop(ownerB.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      const [roundTripVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), ownerA.publicKey.toBuffer()],
+        program.programId
+      );
+      const [delegationPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("delegation"), roundTripVaultPda.toBuffer(), bot.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initialize()
+        .accounts({
+          vault: roundTripVaultPda,
+          user: ownerA.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([ownerA])
+        .rpc();
+      await program.methods
+        .deposit(new BN(100_000_000))
+        .accounts({
+          vault: roundTripVaultPda,
+          user: ownerA.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([ownerA])
+        .rpc();
+
+      // A grants the bot an allowance
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+      await program.methods
+        .grantDelegation(bot.publicKey, new BN(1_000_000), new BN(3600), new BN(now + 3600))
+        .accounts({
+          vault: roundTripVaultPda,
+          config: configPda,
+          delegation: delegationPda,
+          owner: ownerA.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([ownerA])
+        .rpc();
+
+      const transfer = async (from: anchor.web3.Keypair, to: anchor.web3.Keypair) => {
+        await program.methods
+          .proposeOwner(to.publicKey)
+          .accounts({ vault: roundTripVaultPda, config: configPda, owner: from.publicKey })
+          .signers([from])
+          .rpc();
+        await program.methods
+          .acceptOwner()
+          .accounts({ vault: roundTripVaultPda, newOwner: to.publicKey })
+          .signers([to])
+          .rpc();
+      };
+      await transfer(ownerA, ownerB);
+      await transfer(ownerB, ownerA);
+
+      // A owns the vault again, but the epoch moved on twice
+      const vaultAccount = await program.account.vault.fetch(roundTripVaultPda);
+      const delegation = await program.account.delegation.fetch(delegationPda);
+      expect(vaultAccount.owner.toBase58()).to.equal(ownerA.publicKey.toBase58());
+      expect(vaultAccount.ownerEpoch.toNumber()).to.equal(delegation.ownerEpoch.toNumber() + 2);
+
+      try {
+        await program.methods
+          .withdraw(new BN(1_000))
+          .accounts({ vault: roundTripVaultPda, config: configPda, delegation: delegationPda, user: bot.publicKey })
+          .signers([bot])
+          .rpc();
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+        console.log("   ✅ Grant from before the round trip rejected: Unauthorized");
+      }
+
+      // B's turn as owner: revoking A's stale grant refunds the rent to A, not B
+      await transfer(ownerA, ownerB);
+      const rent = await provider.connection.getBalance(delegationPda);
+      const ownerABefore = await provider.connection.getBalance(ownerA.publicKey);
+      await program.methods
+        .revokeDelegation()
+        .accounts({
+          vault: roundTripVaultPda,
+          delegation: delegationPda,
+          owner: ownerB.publicKey,
+          grantedBy: ownerA.publicKey,
+        })
+        .signers([ownerB])
+        .rpc();
+      const ownerAAfter = await provider.connection.getBalance(ownerA.publicKey);
+      expect(ownerAAfter - ownerABefore).to.equal(rent);
+      expect((await program.account.vault.fetch(roundTripVaultPda)).openDelegations.toNumber()).to.equal(0);
+      console.log("   ✅ Revoke sent", rent, "lamports of rent back to the granter");
+    });
+
+    it("Lets the owner cancel a stolen key's ownership transfer before it takes effect", async () => {
+      console.log("\n🧪 TEST: Authority - Time-locked ownership transfer");
+
+      const WITHDRAWAL_DELAY = 3;
+      const setWithdrawalDelay = (delay: number) =>
+        program.methods
+          .updateConfig(
+            new BN(MIN_STAKE_DURATION),
+            new BN(REWARD_RATE_BASIS_POINTS),
+            new BN(COMPOUND_TIP_BASIS_POINTS),
+            new BN(TEST_UNBONDING_PERIOD),
+            new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+            new BN(delay)
+          )
+          .accounts({
+            config: configPda,
+            admin: user.publicKey,
+          })
+          .rpc();
+
+      // The thief holds the owner key and tries to move the vault to a second key
+      const accomplice = anchor.web3.Keypair.generate();
+      await airdrop(accomplice.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await setWithdrawalDelay(WITHDRAWAL_DELAY);
+
+      const sig = await program.methods
+        .proposeOwner(accomplice.publicKey)
+        .accounts({ vault: vaultPda, config: configPda, owner: user.publicKey })
+        .rpc({ commitment: "confirmed" });
+      const proposed = (await eventsFrom(sig)).find((e) => e.name === "ownershipProposed");
+      let vaultAccount = await program.account.vault.fetch(vaultPda);
+      expect(proposed.data.unlockTime.toNumber()).to.equal(vaultAccount.ownerTransferUnlockTime.toNumber());
+
+      const accomplicesAccept = () =>
+        program.methods
+          .acceptOwner()
+          .accounts({ vault: vaultPda, newOwner: accomplice.publicKey })
+          .signers([accomplice])
+          .rpc();
+
+      // Accepting right away fails: the transfer waits out the withdrawal delay
+      try {
+        await accomplicesAccept();
+        expect.fail("Should have thrown OwnershipTransferLocked error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("OwnershipTransferLocked");
+        console.log("   ✅ Correctly rejected: OwnershipTransferLocked");
+      }
+
+      // The real owner sees OwnershipProposed and cancels inside the window
+      await program.methods
+        .proposeOwner(null)
+        .accounts({ vault: vaultPda, config: configPda, owner: user.publicKey })
+        .rpc();
+
+      // Even after the delay the accomplice can't take over
+      await sleep((WITHDRAWAL_DELAY + 1) * 1000);
+      try {
+        await accomplicesAccept();
+        expect.fail("Should have thrown NotPendingOwner error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotPendingOwner");
+        console.log("   ✅ Cancelled transfer can't be accepted: NotPendingOwner");
+      }
+
+      vaultAccount = await program.account.vault.fetch(vaultPda);
+      expect(vaultAccount.owner.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(vaultAccount.pendingOwner).to.be.null;
+
+      await setWithdrawalDelay(TEST_WITHDRAWAL_DELAY);
+    });
+
+    it("Lets the beneficiary claim a vault after the owner goes quiet", async () => {
+      console.log("\n🧪 TEST: Authority - dead-man's switch");
+
+      const owner = anchor.web3.Keypair.generate();
+      const heir = anchor.web3.Keypair.generate();
+      await airdrop(owner.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await airdrop(heir.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      const [heirVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), owner.publicKey.toBuffer()],
+        program.programId
+      );
+      const depositAmount = new BN(100_000_000);
+      const inactivityWindow = 3; // seconds
+
+      await program.methods
+        .initialize()
+        .accounts({
+          vault: heirVaultPda,
+          user: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+      await program.methods
+        .deposit(depositAmount)
+        .accounts({
+          vault: heirVaultPda,
+          user: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+
+      // A beneficiary needs a positive window
+      try {
+        await program.methods
+          .setBeneficiary(heir.publicKey, new BN(0))
+          .accounts({ vault: heirVaultPda, owner: owner.publicKey })
+          .signers([owner])
+          .rpc();
+        expect.fail("Should have thrown InvalidInactivityWindow error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidInactivityWindow");
+        console.log("   ✅ Correctly rejected: InvalidInactivityWindow");
+      }
+
+      await program.methods
+        .setBeneficiary(heir.publicKey, new BN(inactivityWindow))
+        .accounts({ vault: heirVaultPda, owner: owner.publicKey })
+        .signers([owner])
+        .rpc();
+      let vaultAccount = await program.account.vault.fetch(heirVaultPda);
+      expect(vaultAccount.beneficiary.toBase58()).to.equal(heir.publicKey.toBase58());
+      expect(vaultAccount.inactivityWindow.toNumber()).to.equal(inactivityWindow);
+
+      // Only the named beneficiary may claim...
+      try {
+        await program.methods
+          .claimInheritance()
+          .accounts({ vault: heirVaultPda, config: configPda, beneficiary: attacker.publicKey })
+          .signers([attacker])
+          .rpc();
+        expect.fail("Should have thrown NotBeneficiary error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotBeneficiary");
+        console.log("   ✅ Correctly rejected: NotBeneficiary");
+      }
+
+      // ...and a heartbeat keeps the claim closed
+      await sleep((inactivityWindow - 1) * 1000);
+      await program.methods
+        .heartbeat()
+        .accounts({ vault: heirVaultPda, owner: owner.publicKey })
+        .signers([owner])
+        .rpc();
+      await sleep((inactivityWindow - 1) * 1000);
+      try {
+        await program.methods
+          .claimInheritance()
+          .accounts({ vault: heirVaultPda, config: configPda, beneficiary: heir.publicKey })
+          .signers([heir])
+          .rpc();
+        expect.fail("Should have thrown OwnerStillActive error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("OwnerStillActive");
+        console.log("   ✅ Heartbeat kept the vault: OwnerStillActive");
+      }
+
+      // Once the owner stays silent for the whole window, the heir takes over
+      await sleep((inactivityWindow + 1) * 1000);
+      const heirBalanceBefore = await provider.connection.getBalance(heir.publicKey);
+      await program.methods
+        .claimInheritance()
+        .accounts({ vault: heirVaultPda, config: configPda, beneficiary: heir.publicKey })
+        .signers([heir])
+        .rpc();
+      const heirBalanceAfter = await provider.connection.getBalance(heir.publicKey);
+      expect(heirBalanceAfter).to.be.greaterThan(heirBalanceBefore);
+
+      vaultAccount = await program.account.vault.fetch(heirVaultPda);
+      expect(vaultAccount.owner.toBase58()).to.equal(heir.publicKey.toBase58());
+      expect(vaultAccount.beneficiary).to.be.null;
+      expect(vaultAccount.totalDeposited.toNumber()).to.equal(0);
+      console.log("   ✅ Beneficiary received", depositAmount.toNumber(), "lamports and owns the vault");
+
+      // The previous owner is locked out
+      try {
+        await program.methods
+          .heartbeat()
+          .accounts({ vault: heirVaultPda, owner: owner.publicKey })
+          .signers([owner])
+          .rpc();
+        expect.fail("Should have thrown ConstraintHasOne error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ConstraintHasOne");
+        console.log("   ✅ Previous owner correctly rejected: ConstraintHasOne");
+      }
+    });
+  });
+
+  // ==============================================
+  // AUTO-COMPOUND TESTS
+  // ==============================================
+  // compound is a permissionless crank: a stranger re-stakes the owner's
+  // rewards and earns a tip, but only on vaults that opted in.
+
+  describe("auto-compound", () => {
+    const cranker = anchor.web3.Keypair.generate();
+    const stakeAmount = new BN(1_000_000_000);
+    let positionPda: anchor.web3.PublicKey;
+
+    before(async () => {
+      await airdrop(cranker.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await completePendingUnstake();
+
+      // Drop the cliff so the tests don't need to wait 60 seconds
+      await program.methods
+        .updateConfig(new BN(0), new BN(REWARD_RATE_BASIS_POINTS), new BN(COMPOUND_TIP_BASIS_POINTS), new BN(TEST_UNBONDING_PERIOD), new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS), new BN(TEST_WITHDRAWAL_DELAY))
+        .accounts({
+          config: configPda,
+          admin: user.publicKey,
+        })
+        .rpc();
+
+      // Top up the vault so there is something to stake
+      await program.methods
+        .deposit(stakeAmount)
+        .accounts({
+          vault: vaultPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      positionPda = await nextPositionPda();
+      await program.methods
+        .stake(stakeAmount, NO_LOCK)
+        .accounts({
+          vault: vaultPda,
+          stakePosition: positionPda,
+          config: configPda,
+          stakePool: stakePoolPda,
+          user: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Rejects compound on a vault that has not opted in", async () => {
+      console.log("\n🧪 TEST: Error - Compound without opt-in");
+
+      try {
+        await program.methods
+          .compound()
+          .accounts({
+            vault: vaultPda,
+            stakePosition: positionPda,
+            rewardPool: rewardPoolPda,
+            config: configPda,
+            stakePool: stakePoolPda,
+            caller: cranker.publicKey,
+          })
+          .signers([cranker])
+          .rpc();
+
+        expect.fail("Should have thrown AutoCompoundDisabled error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AutoCompoundDisabled");
+        console.log("   ✅ Correctly rejected: AutoCompoundDisabled");
+      }
+    });
+
+    it("Lets anyone compound an opted-in vault for a tip", async () => {
+      console.log("\n🧪 TEST: Compound crank");
+
+      await program.methods
+        .setAutoCompound(true)
+        .accounts({
+          vault: vaultPda,
+          owner: user.publicKey,
+        })
+        .rpc();
+
+      console.log("   ⏰ Waiting 3 seconds for rewards to accrue...");
+      await sleep(3000);
+
+      const positionBefore = await program.account.stakePosition.fetch(positionPda);
+      const vaultBefore = await program.account.vault.fetch(vaultPda);
+      const poolBalanceBefore = await provider.connection.getBalance(rewardPoolPda);
+      const crankerBalanceBefore = await provider.connection.getBalance(cranker.publicKey);
+
+      await program.methods
+        .compound()
+        .accounts({
+          vault: vaultPda,
+          stakePosition: positionPda,
+          rewardPool: rewardPoolPda,
+          config: configPda,
+          stakePool: stakePoolPda,
+          caller: cranker.publicKey,
+        })
+        .signers([cranker])
+        .rpc();
+
+      const positionAfter = await program.account.stakePosition.fetch(positionPda);
+      const vaultAfter = await program.account.vault.fetch(vaultPda);
+      const compounded = positionAfter.amount.toNumber() - positionBefore.amount.toNumber();
+
+      // The reward is re-staked in the same position
+      expect(compounded).to.be.greaterThan(0);
+      expect(vaultAfter.stakedAmount.toNumber() - vaultBefore.stakedAmount.toNumber()).to.equal(compounded);
+      expect(vaultAfter.totalDeposited.toNumber() - vaultBefore.totalDeposited.toNumber()).to.equal(compounded);
+      expect(positionAfter.stakeTimestamp.toNumber()).to.equal(positionBefore.stakeTimestamp.toNumber());
+
+      // The pool paid the reward plus the tip
+      const tip = Math.floor((compounded * COMPOUND_TIP_BASIS_POINTS) / BASIS_POINTS_DIVISOR);
+      const poolBalanceAfter = await provider.connection.getBalance(rewardPoolPda);
+      expect(poolBalanceBefore - poolBalanceAfter).to.equal(compounded + tip);
+
+      // The cranker got the tip (minus its own tx fee)
+      const crankerBalanceAfter = await provider.connection.getBalance(cranker.publicKey);
+      expect(crankerBalanceAfter - crankerBalanceBefore).to.be.greaterThan(tip - 10_000);
+
+      console.log("   ✅ Compounded", compounded, "lamports, tip:", tip);
+    });
+
+    after(async () => {
+      // Cleanup: opt out, unstake and restore the default cliff
+      await program.methods
+        .setAutoCompound(false)
+        .accounts({
+          vault: vaultPda,
+          owner: user.publicKey,
+        })
+        .rpc();
+
+      const position = await program.account.stakePosition.fetch(positionPda);
+      await program.methods
+        .requestUnstake(position.amount, false)
+        .accounts({
+          vault: vaultPda,
+          stakePosition: positionPda,
+          rewardPool: rewardPoolPda,
+          config: configPda,
+          stakePool: stakePoolPda,
+          user: user.publicKey,
+          payer: user.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .updateConfig(
+          new BN(MIN_STAKE_DURATION),
+          new BN(REWARD_RATE_BASIS_POINTS),
+          new BN(COMPOUND_TIP_BASIS_POINTS),
+          new BN(TEST_UNBONDING_PERIOD),
+          new BN(EARLY_UNSTAKE_PENALTY_BASIS_POINTS),
+          new BN(TEST_WITHDRAWAL_DELAY)
+        )
+        .accounts({
+          config: configPda,
+          admin: user.publicKey,
+        })
+        .rpc();
+    });
+  });
+
+  // ==============================================
+  // MULTISIG TESTS
+  // ==============================================
+  // A 2-of-3 treasury: withdrawals are Proposal PDAs that need two approvals.
+
+  describe("multisig", () => {
+    const alice = anchor.web3.Keypair.generate();
+    const bob = anchor.web3.Keypair.generate();
+    const carol = anchor.web3.Keypair.generate();
+    const outsider = anchor.web3.Keypair.generate();
+    const recipient = anchor.web3.Keypair.generate();
+    let multisigPda: anchor.web3.PublicKey;
+
+    function findProposalPda(index: number): anchor.web3.PublicKey {
+      const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), multisigPda.toBuffer(), new BN(index).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      return pda;
+    }
+
+    before(async () => {
+      await airdrop(alice.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      await airdrop(outsider.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+      [multisigPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig"), user.publicKey.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Fails to create a multisig with a threshold above the signer count", async () => {
+      console.log("\n🧪 TEST: Multisig - Invalid threshold");
+
+      try {
+        await program.methods
+          .createMultisig([alice.publicKey, bob.publicKey], 3)
+          .accounts({
+            multisig: multisigPda,
+            creator: user.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidMultisigConfig error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidMultisigConfig");
+        console.log("   ✅ Correctly rejected: InvalidMultisigConfig");
+      }
+    });
+
+    it("Withdraws from a 2-of-3 multisig only after two approvals", async () => {
+      console.log("\n🧪 TEST: Multisig - Propose, approve, execute");
+
+      await program.methods
+        .createMultisig([alice.publicKey, bob.publicKey, carol.publicKey], 2)
+        .accounts({
+          multisig: multisigPda,
+          creator: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const depositAmount = new BN(500_000_000);
+      await program.methods
+        .depositMultisig(depositAmount)
+        .accounts({
+          multisig: multisigPda,
+          depositor: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      // Alice proposes (and thereby approves)
+      const withdrawAmount = new BN(200_000_000);
+      const proposalPda = findProposalPda(0);
+      await program.methods
+        .proposeWithdrawal(withdrawAmount, recipient.publicKey)
+        .accounts({
+          multisig: multisigPda,
+          proposal: proposalPda,
+          proposer: alice.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([alice])
+        .rpc();
+
+      const execute = (signer: anchor.web3.Keypair) =>
+        program.methods
+          .executeProposal()
+          .accounts({
+            multisig: multisigPda,
+            proposal: proposalPda,
+            recipient: recipient.publicKey,
+            signer: signer.publicKey,
+          })
+          .signers([signer])
+          .rpc();
+
+      // One approval is not enough
+      try {
+        await execute(alice);
+        expect.fail("Should have thrown ThresholdNotMet error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ThresholdNotMet");
+        console.log("   ✅ Correctly rejected with 1 of 2 approvals: ThresholdNotMet");
+      }
+
+      // Alice can't approve twice, and outsiders can't approve at all
+      try {
+        await program.methods
+          .approveProposal()
+          .accounts({ multisig: multisigPda, proposal: proposalPda, signer: alice.publicKey })
+          .signers([alice])
+          .rpc();
+        expect.fail("Should have thrown AlreadyApproved error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AlreadyApproved");
+        console.log("   ✅ Correctly rejected: AlreadyApproved");
+      }
+      try {
+        await program.methods
+          .approveProposal()
+          .accounts({ multisig: multisigPda, proposal: proposalPda, signer: outsider.publicKey })
+          .signers([outsider])
+          .rpc();
+        expect.fail("Should have thrown NotMultisigSigner error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotMultisigSigner");
+        console.log("   ✅ Correctly rejected: NotMultisigSigner");
+      }
+
+      // Bob's approval reaches the threshold
+      await program.methods
+        .approveProposal()
+        .accounts({ multisig: multisigPda, proposal: proposalPda, signer: bob.publicKey })
+        .signers([bob])
+        .rpc();
+
+      await execute(bob);
+
+      const recipientBalance = await provider.connection.getBalance(recipient.publicKey);
+      expect(recipientBalance).to.equal(withdrawAmount.toNumber());
+      const multisigAccount = await program.account.multisigVault.fetch(multisigPda);
+      expect(multisigAccount.totalDeposited.toNumber()).to.equal(
+        depositAmount.sub(withdrawAmount).toNumber()
+      );
+      const proposal = await program.account.proposal.fetch(proposalPda);
+      expect(proposal.executed).to.be.true;
+      expect(proposal.approvals.length).to.equal(2);
+      console.log("   ✅ Executed with 2 of 3 approvals:", withdrawAmount.toNumber(), "lamports sent");
+
+      // A proposal can only be executed once
+      try {
+        await execute(carol);
+        expect.fail("Should have thrown ProposalAlreadyExecuted error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ProposalAlreadyExecuted");
+        console.log("   ✅ Correctly rejected replay: ProposalAlreadyExecuted");
+      }
+    });
   });
 
   // ==============================================
@@ -495,7 +3651,8 @@ describe("vault-staking", () => {
     console.log("   Owner:", vaultAccount.owner.toString());
     console.log("   Total Deposited:", vaultAccount.totalDeposited.toNumber(), "lamports");
     console.log("   Staked Amount:", vaultAccount.stakedAmount.toNumber(), "lamports");
-    console.log("   Is Staked:", vaultAccount.isStaked);
+    console.log("   Positions Opened:", vaultAccount.nextPositionIndex.toNumber());
+    console.log("   Pending Unstake:", vaultAccount.pendingUnstake.toNumber(), "lamports");
     console.log("=".repeat(50) + "\n");
   });
 });